@@ -0,0 +1,234 @@
+/*! # Incomparable Elements
+
+ All sorting and searching algorithms in this crate work on partial orders, via `PartialOrd`. For
+ most types that makes no difference, but floating point numbers have NaN, which is neither smaller
+ than, larger than, nor equal to anything, itself included. The algorithms treat such an element as
+ equal to everything, which isn't transitive: `1.0` and `2.0` are both "equal" to NaN, but not to
+ each other. A slice containing NaNs thus comes out of a sort only partially sorted, in some order
+ depending on the algorithm, and a binary search in it may miss elements that are there.
+
+ This module makes the outcome well-defined, with an `Incomparable` policy chosen per call. We
+ consider an element incomparable if it can't be compared to itself, which for floats are exactly
+ the NaNs. The policy can be to
+  * `Fail`, reporting the index of the first incomparable element as an `IncomparableError`,
+  * order incomparable elements `First` or `Last`, which makes for a total order again, or
+  * treat them as `Equal` to everything, which is what the algorithms do by themselves.
+
+ Any of the `_by` variants of the sorting algorithms can be used with a policy, through
+ `Incomparable::sort`, the parallel merge sort included. For a policy that doesn't fail,
+ `Incomparable::compare` can also be passed to them directly. The two sorts that don't compare
+ elements by a function of our choosing, `bucket_sort` and `young_tableau_sort`, go through
+ `Incomparable::sort_with` instead, which sets the incomparable elements aside before sorting the
+ others. Counting and radix sort work on integer keys, which are always comparable. Searches use
+ `Incomparable::binary_search` and `Incomparable::linear_search`.
+*/
+use std::{cmp::Ordering, error::Error, fmt, ops::Range};
+
+use crate::{
+    searching::{binary_search::binary_search_by, linear_search::linear_search_by},
+    util::{cmp::partial_compare, slice},
+};
+
+#[cfg(test)]
+mod test;
+
+/// What to do with elements that can't be compared, see the module documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Incomparable {
+    /// Refuse to sort or search data containing incomparable elements.
+    Fail,
+    /// Order incomparable elements before all others.
+    First,
+    /// Order incomparable elements after all others.
+    Last,
+    /// Treat incomparable elements as equal to all others.
+    Equal,
+}
+
+/// The error reported by the `Fail` policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IncomparableError {
+    /// The index of the (first) incomparable element.
+    pub index: usize,
+}
+
+impl fmt::Display for IncomparableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Incomparable element at index {}", self.index)
+    }
+}
+
+impl Error for IncomparableError {}
+
+/// Whether `x` is incomparable, i.e. can't even be compared to itself.
+pub fn is_incomparable<T>(x: &T) -> bool
+where
+    T: PartialOrd + ?Sized,
+{
+    x.partial_cmp(x).is_none()
+}
+
+impl Incomparable {
+    /// Compare `a` and `b` according to the policy. `Fail` compares like `Equal`, as the
+    /// incomparable elements are supposed to have been ruled out by `check` beforehand.
+    pub fn compare<T>(self, a: &T, b: &T) -> Ordering
+    where
+        T: PartialOrd + ?Sized,
+    {
+        // Comparable elements take the fast path, only incomparable pairs need a closer look.
+        if let Some(ordering) = a.partial_cmp(b) {
+            return ordering;
+        }
+
+        let (a_incomparable, b_incomparable) = (is_incomparable(a), is_incomparable(b));
+        let incomparable_first = match (a_incomparable, b_incomparable) {
+            (true, true) | (false, false) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        };
+
+        match self {
+            Incomparable::First => incomparable_first,
+            Incomparable::Last => incomparable_first.reverse(),
+            Incomparable::Fail | Incomparable::Equal => partial_compare(a, b),
+        }
+    }
+
+    /// Check `data` for incomparable elements, failing on the first one if the policy is `Fail`.
+    /// Other policies always succeed.
+    pub fn check<T>(self, data: &[T]) -> Result<(), IncomparableError>
+    where
+        T: PartialOrd,
+    {
+        if self != Incomparable::Fail {
+            return Ok(());
+        }
+
+        match data.iter().position(is_incomparable) {
+            Some(index) => Err(IncomparableError { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Sort `data` with `sort_by`, which is given the comparison function of the policy. With the
+    /// `Fail` policy, `data` is left untouched if it contains an incomparable element.
+    ///
+    /// ```
+    /// use cl_rs::{incomparable::Incomparable, sorting::merge_sort::merge_sort_by};
+    ///
+    /// let mut data = [3.0, f64::NAN, 1.0, 2.0];
+    /// Incomparable::Last.sort(&mut data, |d, compare| merge_sort_by(d, compare)).unwrap();
+    /// assert_eq!(data[..3], [1.0, 2.0, 3.0]);
+    /// assert!(data[3].is_nan());
+    /// ```
+    pub fn sort<T, S>(self, data: &mut [T], sort_by: S) -> Result<(), IncomparableError>
+    where
+        T: PartialOrd,
+        S: FnOnce(&mut [T], &(dyn Fn(&T, &T) -> Ordering + Sync)),
+    {
+        self.check(data)?;
+        sort_by(data, &|a, b| self.compare(a, b));
+        Ok(())
+    }
+
+    /// Sort `data` with `sort`, for algorithms that can't be given a comparison function. With
+    /// `First` and `Last`, the incomparable elements are moved to the front or back of `data`,
+    /// keeping their order, and `sort` only gets to see the others. With `Equal`, `sort` gets all
+    /// of `data` and has to cope with incomparable elements itself, which `bucket_sort` doesn't.
+    pub fn sort_with<T, S>(self, data: &mut [T], sort: S) -> Result<(), IncomparableError>
+    where
+        T: PartialOrd,
+        S: FnOnce(&mut [T]),
+    {
+        self.check(data)?;
+
+        let comparable = match self {
+            Incomparable::First | Incomparable::Last => set_aside(data, self),
+            Incomparable::Fail | Incomparable::Equal => 0..data.len(),
+        };
+        sort(&mut data[comparable]);
+        Ok(())
+    }
+
+    /// Binary search for `value` in `data`, which has to be sorted according to the policy. With
+    /// the `Fail` policy, running into an incomparable element is an error, and an incomparable
+    /// `value` is never found. With `Equal`, an incomparable `value` matches any element.
+    pub fn binary_search<T>(self, data: &[T], value: &T) -> Result<Option<usize>, IncomparableError>
+    where
+        T: PartialOrd,
+    {
+        self.search(value, |compare| binary_search_by(data, compare))
+    }
+
+    /// Linear search for `value` in `data`, finding the first element equal to it according to
+    /// the policy. With the `Fail` policy, running into an incomparable element before finding
+    /// `value` is an error, and an incomparable `value` is never found. With `Equal`, an
+    /// incomparable `value` matches any element.
+    pub fn linear_search<T>(self, data: &[T], value: &T) -> Result<Option<usize>, IncomparableError>
+    where
+        T: PartialOrd,
+    {
+        self.search(value, |compare| linear_search_by(data, compare))
+    }
+
+    // Run `search_by` with a comparison to `value` according to the policy. To fail, the
+    // comparison reports an incomparable element as a match, which ends the search right there
+    // with its index.
+    fn search<T, S>(self, value: &T, search_by: S) -> Result<Option<usize>, IncomparableError>
+    where
+        T: PartialOrd,
+        S: FnOnce(&mut dyn FnMut(&T) -> Ordering) -> Option<usize>,
+    {
+        let fail = self == Incomparable::Fail;
+        if fail && is_incomparable(value) {
+            return Ok(None);
+        }
+
+        let mut failed = false;
+        let index = search_by(&mut |x| {
+            if fail && is_incomparable(x) {
+                failed = true;
+                return Ordering::Equal;
+            }
+
+            self.compare(x, value)
+        });
+
+        match index {
+            Some(index) if failed => Err(IncomparableError { index }),
+            index => Ok(index),
+        }
+    }
+}
+
+// Move the incomparable elements of `data` to its front for `First`, or to its back for `Last`,
+// keeping the order within both groups. Returns the range of the comparable elements.
+fn set_aside<T>(data: &mut [T], policy: Incomparable) -> Range<usize>
+where
+    T: PartialOrd,
+{
+    let incomparable: Vec<_> = data.iter().map(is_incomparable).collect();
+    let count = incomparable.iter().filter(|&&x| x).count();
+
+    let (comparable, mut next_incomparable) = match policy {
+        Incomparable::First => (count..data.len(), 0),
+        _ => (0..data.len() - count, data.len() - count),
+    };
+
+    let mut next_comparable = comparable.start;
+    let mut destinations: Vec<_> = incomparable
+        .into_iter()
+        .map(|x| {
+            let next = if x {
+                &mut next_incomparable
+            } else {
+                &mut next_comparable
+            };
+            *next += 1;
+            *next - 1
+        })
+        .collect();
+
+    slice::permute(data, &mut destinations);
+    comparable
+}
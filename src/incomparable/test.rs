@@ -0,0 +1,247 @@
+use std::cmp::Ordering;
+
+use quickcheck_macros::quickcheck;
+
+use super::{is_incomparable, Incomparable, IncomparableError};
+use crate::{
+    sorting::{
+        binary_insertion_sort::binary_insertion_sort_by,
+        bubble_sort::bubble_sort_by,
+        bucket_sort::bucket_sort,
+        heap_sort::{d_ary::d_ary_heap_sort_by, heap_sort_by},
+        insertion_sort::insertion_sort_by,
+        merge_sort::{
+            bottom_up::bottom_up_merge_sort_by, indirect::indirect_merge_sort_by, merge_sort_by,
+            parallel::parallel_merge_sort_by,
+        },
+        quick_sort::{
+            hoare_quick_sort_by, median_of_three_quick_sort_by, quick_sort_by,
+            randomized_quick_sort_by, tail_recursive_quick_sort_by, three_way_quick_sort_by,
+        },
+        selection_sort::selection_sort_by,
+    },
+    young_tableau::young_tableau_sort,
+};
+
+type SortBy = fn(&mut [f64], &(dyn Fn(&f64, &f64) -> Ordering + Sync));
+
+// All the sorting algorithms taking a comparison function.
+const SORTS: [SortBy; 16] = [
+    |d, c| insertion_sort_by(d, c),
+    |d, c| selection_sort_by(d, c),
+    |d, c| bubble_sort_by(d, c),
+    |d, c| binary_insertion_sort_by(d, c),
+    |d, c| merge_sort_by(d, c),
+    |d, c| bottom_up_merge_sort_by(d, c),
+    |d, c| indirect_merge_sort_by(d, c),
+    |d, c| parallel_merge_sort_by(d, c),
+    |d, c| heap_sort_by(d, c),
+    |d, c| d_ary_heap_sort_by::<_, _, 4>(d, c),
+    |d, c| quick_sort_by(d, c),
+    |d, c| hoare_quick_sort_by(d, c),
+    |d, c| randomized_quick_sort_by(d, c),
+    |d, c| three_way_quick_sort_by(d, c),
+    |d, c| tail_recursive_quick_sort_by(d, c),
+    |d, c| median_of_three_quick_sort_by(d, c),
+];
+
+// Turn some of the numbers into NaNs.
+fn with_nans(data: Vec<i16>) -> Vec<f64> {
+    data.into_iter()
+        .map(|d| if d % 5 == 0 { f64::NAN } else { f64::from(d) })
+        .collect()
+}
+
+// Whether `sorted` has its NaNs in the place the policy demands, and the other numbers of `data`
+// sorted.
+fn is_sorted_with_nans(policy: Incomparable, data: &[f64], sorted: &[f64]) -> bool {
+    let nans = data.iter().filter(|d| d.is_nan()).count();
+    let mut numbers: Vec<_> = data.iter().copied().filter(|d| !d.is_nan()).collect();
+    numbers.sort_by(f64::total_cmp);
+
+    let (nan_part, number_part) = match policy {
+        Incomparable::First => sorted.split_at(nans),
+        _ => {
+            let (numbers, nans) = sorted.split_at(sorted.len() - nans);
+            (nans, numbers)
+        }
+    };
+    nan_part.iter().all(|d| d.is_nan()) && number_part == numbers
+}
+
+fn sorts_nans(policy: Incomparable, data: Vec<i16>) -> bool {
+    let data = with_nans(data);
+
+    SORTS.iter().all(|sort_by| {
+        let mut sorted = data.clone();
+        policy.sort(&mut sorted, sort_by).unwrap();
+        is_sorted_with_nans(policy, &data, &sorted)
+    })
+}
+
+// Bucket sort needs its numbers in `[0, 1)`.
+fn sets_nans_aside(policy: Incomparable, data: Vec<i16>) -> bool {
+    let data: Vec<_> = with_nans(data)
+        .into_iter()
+        .map(|d| (d + 32768.0) / 65536.0)
+        .collect();
+
+    let mut bucket_sorted = data.clone();
+    policy.sort_with(&mut bucket_sorted, bucket_sort).unwrap();
+    let mut tableau_sorted = data.clone();
+    policy
+        .sort_with(&mut tableau_sorted, young_tableau_sort)
+        .unwrap();
+
+    is_sorted_with_nans(policy, &data, &bucket_sorted)
+        && is_sorted_with_nans(policy, &data, &tableau_sorted)
+}
+
+#[quickcheck]
+fn nans_first(data: Vec<i16>) -> bool {
+    sorts_nans(Incomparable::First, data)
+}
+
+#[quickcheck]
+fn nans_last(data: Vec<i16>) -> bool {
+    sorts_nans(Incomparable::Last, data)
+}
+
+#[quickcheck]
+fn nans_first_without_compare(data: Vec<i16>) -> bool {
+    sets_nans_aside(Incomparable::First, data)
+}
+
+#[quickcheck]
+fn nans_last_without_compare(data: Vec<i16>) -> bool {
+    sets_nans_aside(Incomparable::Last, data)
+}
+
+#[test]
+fn sort_with_fails() {
+    let mut data = [0.5, f64::NAN, 0.25];
+    let result = Incomparable::Fail.sort_with(&mut data, bucket_sort);
+
+    assert_eq!(result, Err(IncomparableError { index: 1 }));
+    assert_eq!(data[0], 0.5);
+}
+
+#[quickcheck]
+fn fails_on_nans(data: Vec<i16>) -> bool {
+    let data = with_nans(data);
+    let mut sorted = data.clone();
+    let result = Incomparable::Fail.sort(&mut sorted, |d, c| merge_sort_by(d, c));
+
+    match data.iter().position(|d| d.is_nan()) {
+        Some(index) => {
+            result == Err(IncomparableError { index })
+                && sorted
+                    .iter()
+                    .zip(&data)
+                    .all(|(a, b)| a.total_cmp(b).is_eq())
+        }
+        None => result.is_ok() && sorted.is_sorted(),
+    }
+}
+
+#[quickcheck]
+fn nans_equal(data: Vec<i16>) -> bool {
+    let data = with_nans(data);
+    let mut sorted = data.clone();
+    let result = Incomparable::Equal.sort(&mut sorted, |d, c| merge_sort_by(d, c));
+
+    // There's no telling what the order will be like, but all the elements are still there.
+    let mut data_bits: Vec<_> = data.iter().map(|d| d.to_bits()).collect();
+    let mut sorted_bits: Vec<_> = sorted.iter().map(|d| d.to_bits()).collect();
+    data_bits.sort_unstable();
+    sorted_bits.sort_unstable();
+    result.is_ok() && data_bits == sorted_bits
+}
+
+#[quickcheck]
+fn binary_search(data: Vec<i16>, value: i16) -> bool {
+    let data = with_nans(data);
+    let value = f64::from(value);
+
+    [Incomparable::First, Incomparable::Last]
+        .iter()
+        .all(|&policy| {
+            let mut data = data.clone();
+            policy.sort(&mut data, |d, c| merge_sort_by(d, c)).unwrap();
+
+            let found = policy.binary_search(&data, &value).unwrap();
+            let found_nan = policy.binary_search(&data, &f64::NAN).unwrap();
+
+            found.is_some() == data.contains(&value)
+                && found.is_none_or(|i| data[i] == value)
+                && found_nan.is_some() == data.iter().any(|d| d.is_nan())
+                && found_nan.is_none_or(|i| data[i].is_nan())
+        })
+}
+
+#[quickcheck]
+fn linear_search(data: Vec<i16>, value: i16) -> bool {
+    let data = with_nans(data);
+    let value = f64::from(value);
+
+    let found = Incomparable::Last.linear_search(&data, &value).unwrap();
+    let found_nan = Incomparable::Last.linear_search(&data, &f64::NAN).unwrap();
+    let found_any = Incomparable::Equal.linear_search(&data, &f64::NAN).unwrap();
+
+    found == data.iter().position(|&d| d == value)
+        && found_nan == data.iter().position(|d| d.is_nan())
+        && found_any == (!data.is_empty()).then_some(0)
+}
+
+#[test]
+fn linear_search_fails() {
+    let data = [1.0, 2.0, f64::NAN, 4.0, 5.0];
+    let policy = Incomparable::Fail;
+
+    assert_eq!(policy.linear_search(&data, &2.0), Ok(Some(1)));
+    assert_eq!(
+        policy.linear_search(&data, &4.0),
+        Err(IncomparableError { index: 2 })
+    );
+    assert_eq!(policy.linear_search(&data, &f64::NAN), Ok(None));
+}
+
+#[test]
+fn binary_search_fails() {
+    let data = [1.0, 2.0, f64::NAN, 4.0, 5.0];
+    let policy = Incomparable::Fail;
+
+    assert_eq!(
+        policy.binary_search(&data, &3.0),
+        Err(IncomparableError { index: 2 })
+    );
+    assert_eq!(policy.binary_search(&data, &f64::NAN), Ok(None));
+    assert_eq!(policy.binary_search(&data[3..], &5.0), Ok(Some(1)));
+}
+
+#[test]
+fn incomparability() {
+    assert!(is_incomparable(&f64::NAN));
+    assert!(!is_incomparable(&f64::INFINITY));
+    assert!(!is_incomparable(&-0.0));
+}
+
+#[test]
+fn error_message() {
+    let error = IncomparableError { index: 3 };
+    assert_eq!(error.to_string(), "Incomparable element at index 3");
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<f64> = Vec::new();
+    let policy = Incomparable::Fail;
+
+    assert_eq!(
+        policy.sort(&mut nothing, |d, c| merge_sort_by(d, c)),
+        Ok(())
+    );
+    assert_eq!(policy.binary_search(&nothing, &1.0), Ok(None));
+}
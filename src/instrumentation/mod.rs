@@ -0,0 +1,267 @@
+/*! # Instrumentation
+
+ Asymptotic running times are all well and good, but sometimes one wants to see the actual number
+ of operations an algorithm performs, e.g. to check that insertion sort really does `Θ(n^2)`
+ comparisons on reversed input, while merge sort gets by with `O(n lg n)`. This module lets the
+ algorithms from `sorting` and `searching` be run on elements wrapped in `Counted<T>`, which tally
+ up what is done to them, without changing the algorithms themselves.
+
+ Comparisons and clones are easy to count, since they go through the elements' trait
+ implementations. Writes are counted when an element of the slice is overwritten, which drops the
+ previous element; values dropped anywhere else, like clones in a temporary buffer, don't count.
+ Moving elements around is harder to notice, since a move just copies bytes. So the algorithms move
+ their elements by means of a few functions wrapping `<[T]>::swap` and friends, which check
+ whether the elements belong to an instrumented slice, and count a swap, or a write for every
+ element that ends up in the slice. This works from any thread: `Counted<T>` is `Send` and `Sync`
+ whenever `T` is, so `parallel_merge_sort` can be instrumented as well.
+
+ `Counted<T>` implements `Radix` and `UnitInterval` whenever `T` does, so `radix_sort` and
+ `bucket_sort` take instrumented elements too, and `counting_sort` does with a key function that
+ looks through the wrapper. The sorting functions that don't sort slices of plain elements are out
+ of scope, namely `fuzzy_sort` on intervals, `external_sort` and `k_way_merge` on streams, and
+ `young_tableau_search` on matrices.
+*/
+use std::{
+    cmp::Ordering,
+    fmt, mem,
+    ops::{Deref, Range},
+    sync::{
+        atomic::{self, AtomicUsize},
+        Arc, RwLock,
+    },
+};
+
+use crate::sorting::{bucket_sort::UnitInterval, radix_sort::Radix};
+
+#[cfg(test)]
+mod test;
+
+/// The operations counted during one instrumented run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Calls to any of the comparison operators.
+    pub comparisons: usize,
+    /// Exchanges of two elements of the slice, including those of an element with itself.
+    pub swaps: usize,
+    /// Elements of the slice that were overwritten, by assignment or by moving in an element from
+    /// outside of the slice.
+    pub writes: usize,
+    /// Copies made of elements, e.g. into temporary buffers.
+    pub clones: usize,
+}
+
+#[derive(Debug, Default)]
+struct Counter {
+    comparisons: AtomicUsize,
+    swaps: AtomicUsize,
+    writes: AtomicUsize,
+    clones: AtomicUsize,
+}
+
+impl Counter {
+    fn add(count: &AtomicUsize, n: usize) {
+        count.fetch_add(n, atomic::Ordering::Relaxed);
+    }
+
+    fn report(&self) -> Report {
+        Report {
+            comparisons: self.comparisons.load(atomic::Ordering::Relaxed),
+            swaps: self.swaps.load(atomic::Ordering::Relaxed),
+            writes: self.writes.load(atomic::Ordering::Relaxed),
+            clones: self.clones.load(atomic::Ordering::Relaxed),
+        }
+    }
+}
+
+// The runs in progress, each given by the addresses its slice occupies along with its counter.
+// Moves and drops only tell us the addresses of the elements involved, so this is how we find out
+// which run, if any, to count them for.
+static RUNS: RwLock<Vec<(Range<usize>, Arc<Counter>)>> = RwLock::new(Vec::new());
+
+// The number of runs in progress, which spares uninstrumented code the lock. Threads working on an
+// instrumented slice got hold of it after its run started, so they see a positive count.
+static RUN_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// Registers the slice of a run while it's in progress.
+struct Run {
+    counter: Arc<Counter>,
+}
+
+impl Run {
+    fn start<T>(data: &[T], counter: &Arc<Counter>) -> Run {
+        let start = data.as_ptr() as usize;
+        let slice = start..start + mem::size_of_val(data);
+
+        RUNS.write().unwrap().push((slice, Arc::clone(counter)));
+        RUN_COUNT.fetch_add(1, atomic::Ordering::Relaxed);
+
+        Run {
+            counter: Arc::clone(counter),
+        }
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        RUNS.write()
+            .unwrap()
+            .retain(|(_, counter)| !Arc::ptr_eq(counter, &self.counter));
+        RUN_COUNT.fetch_sub(1, atomic::Ordering::Relaxed);
+    }
+}
+
+// Add `n` to the count that `select` picks from the counter of the run whose slice `element` lies
+// in, if any.
+fn count<T>(element: &T, n: usize, select: fn(&Counter) -> &AtomicUsize) {
+    if RUN_COUNT.load(atomic::Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let address = element as *const T as usize;
+    let runs = RUNS.read().unwrap();
+    if let Some((_, counter)) = runs.iter().find(|(slice, _)| slice.contains(&address)) {
+        Counter::add(select(counter), n);
+    }
+}
+
+/// Count a swap of `element` with another one, if it lies in an instrumented slice.
+pub(crate) fn count_swap<T>(element: &T) {
+    count(element, 1, |counter| &counter.swaps);
+}
+
+/// Count `n` writes to the slice that `element` lies in, if it is instrumented.
+pub(crate) fn count_writes<T>(element: &T, n: usize) {
+    count(element, n, |counter| &counter.writes);
+}
+
+/// An element that counts the operations performed on it.
+pub struct Counted<T> {
+    // Always `Some` until the element is unwrapped at the end of the run; we need to be able to
+    // move the value out despite implementing `Drop`.
+    value: Option<T>,
+    counter: Arc<Counter>,
+}
+
+impl<T> Counted<T> {
+    fn new(value: T, counter: &Arc<Counter>) -> Counted<T> {
+        Counted {
+            value: Some(value),
+            counter: Arc::clone(counter),
+        }
+    }
+
+    /// The wrapped value.
+    pub fn value(&self) -> &T {
+        self.value.as_ref().unwrap()
+    }
+
+    fn into_inner(mut self) -> T {
+        self.value.take().unwrap()
+    }
+}
+
+impl<T> Deref for Counted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Counted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: Clone> Clone for Counted<T> {
+    fn clone(&self) -> Self {
+        Counter::add(&self.counter.clones, 1);
+        Counted::new(self.value().clone(), &self.counter)
+    }
+}
+
+impl<T> Drop for Counted<T> {
+    fn drop(&mut self) {
+        // Being dropped in place means being overwritten, at least within the slice.
+        if self.value.is_some() {
+            count_writes(self, 1);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Counted<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Counter::add(&self.counter.comparisons, 1);
+        self.value() == other.value()
+    }
+}
+
+// The other comparison operators are implemented in terms of `partial_cmp`, and hence count once.
+impl<T: PartialOrd> PartialOrd for Counted<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Counter::add(&self.counter.comparisons, 1);
+        self.value().partial_cmp(other.value())
+    }
+}
+
+impl<T: Radix> Radix for Counted<T> {
+    fn radix(width: u32) -> usize {
+        T::radix(width)
+    }
+
+    fn digit_count(&self, width: u32) -> usize {
+        self.value().digit_count(width)
+    }
+
+    fn digit(&self, index: usize, width: u32) -> usize {
+        self.value().digit(index, width)
+    }
+}
+
+impl<T: UnitInterval> UnitInterval for Counted<T> {
+    fn unit_value(&self) -> f64 {
+        self.value().unit_value()
+    }
+}
+
+/// Run the sorting algorithm `sort` on `data`, counting the operations it performs. Returns the
+/// sorted data along with the report.
+pub fn instrument_sort<T, F>(data: Vec<T>, sort: F) -> (Vec<T>, Report)
+where
+    F: FnOnce(&mut [Counted<T>]),
+{
+    let counter = Arc::new(Counter::default());
+    let mut data: Vec<_> = data
+        .into_iter()
+        .map(|d| Counted::new(d, &counter))
+        .collect();
+
+    let run = Run::start(&data, &counter);
+    sort(&mut data);
+    drop(run);
+
+    let data = data.into_iter().map(Counted::into_inner).collect();
+    (data, counter.report())
+}
+
+/// Run the searching algorithm `search` for `value` in `data`, counting the operations it
+/// performs. Returns the search result along with the report.
+pub fn instrument_search<T, R, F>(data: &[T], value: T, search: F) -> (R, Report)
+where
+    T: Clone,
+    F: FnOnce(&[Counted<T>], Counted<T>) -> R,
+{
+    let counter = Arc::new(Counter::default());
+    let data: Vec<_> = data
+        .iter()
+        .map(|d| Counted::new(d.clone(), &counter))
+        .collect();
+    let value = Counted::new(value, &counter);
+
+    let run = Run::start(&data, &counter);
+    let result = search(&data, value);
+    drop(run);
+
+    (result, counter.report())
+}
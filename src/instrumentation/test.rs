@@ -0,0 +1,318 @@
+use quickcheck_macros::quickcheck;
+
+use super::{instrument_search, instrument_sort, Counted, Report};
+use crate::{
+    searching::{binary_search::binary_search, linear_search::linear_search},
+    sorting::{
+        bucket_sort::bucket_sort,
+        counting_sort::counting_sort,
+        heap_sort::heap_sort,
+        insertion_sort::insertion_sort,
+        merge_sort::{
+            bottom_up::bottom_up_merge_sort,
+            merge_sort, merge_sort_with_config,
+            parallel::{parallel_merge_sort_with_config, ParallelMergeSortConfig},
+            MergeSortConfig,
+        },
+        quick_sort::quick_sort,
+        radix_sort::radix_sort,
+        selection_sort::selection_sort,
+        sorter::Registry,
+    },
+    testing::vector::ElementOf,
+};
+
+// Heapsort and quicksort as given in the book, with their comparisons and swaps counted by hand,
+// to check the instrumented counts against.
+#[derive(Debug, Default)]
+struct Reference {
+    comparisons: usize,
+    swaps: usize,
+}
+
+impl Reference {
+    fn less(&mut self, a: i32, b: i32) -> bool {
+        self.comparisons += 1;
+        a < b
+    }
+
+    fn swap(&mut self, data: &mut [i32], i: usize, j: usize) {
+        self.swaps += 1;
+        data.swap(i, j);
+    }
+
+    fn max_heapify(&mut self, data: &mut [i32], mut i: usize) {
+        loop {
+            let (l, r) = (2 * i + 1, 2 * i + 2);
+
+            let mut largest = i;
+            if l < data.len() && self.less(data[largest], data[l]) {
+                largest = l;
+            }
+            if r < data.len() && self.less(data[largest], data[r]) {
+                largest = r;
+            }
+
+            if largest == i {
+                return;
+            }
+
+            self.swap(data, i, largest);
+            i = largest;
+        }
+    }
+
+    fn heap_sort(&mut self, data: &mut [i32]) {
+        for i in (0..data.len() / 2).rev() {
+            self.max_heapify(data, i);
+        }
+
+        for end in (0..data.len()).rev() {
+            self.swap(data, 0, end);
+            self.max_heapify(&mut data[..end], 0);
+        }
+    }
+
+    fn quick_sort(&mut self, data: &mut [i32]) {
+        if data.len() <= 1 {
+            return;
+        }
+
+        let r = data.len() - 1;
+        let mut i = 0;
+        for j in 0..r {
+            if !self.less(data[r], data[j]) {
+                self.swap(data, i, j);
+                i += 1;
+            }
+        }
+        self.swap(data, i, r);
+
+        self.quick_sort(&mut data[..i]);
+        self.quick_sort(&mut data[i + 1..]);
+    }
+
+    fn report(&self) -> Report {
+        Report {
+            comparisons: self.comparisons,
+            swaps: self.swaps,
+            ..Report::default()
+        }
+    }
+}
+
+// The minimum number of comparisons any comparison sort needs in the worst case, `lg(n!)`.
+fn comparison_lower_bound(n: usize) -> usize {
+    (2..=n).map(|i| (i as f64).log2()).sum::<f64>().ceil() as usize
+}
+
+#[quickcheck]
+fn sortedness_preservation(data: Vec<i32>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    Registry::<Counted<i32>>::comparison_sorts()
+        .iter()
+        .all(|sorter| instrument_sort(data.clone(), |d| sorter.sort(d)).0 == expected)
+}
+
+#[quickcheck]
+fn heap_sort_matches_reference(mut data: Vec<i32>) -> bool {
+    let (_, report) = instrument_sort(data.clone(), heap_sort);
+
+    let mut reference = Reference::default();
+    reference.heap_sort(&mut data);
+
+    report == reference.report()
+}
+
+#[quickcheck]
+fn quick_sort_matches_reference(mut data: Vec<i32>) -> bool {
+    let (_, report) = instrument_sort(data.clone(), quick_sort);
+
+    let mut reference = Reference::default();
+    reference.quick_sort(&mut data);
+
+    report == reference.report()
+}
+
+// All comparison sorts move their elements by swapping, writing or both, so an unsorted input has
+// to show up in either count; and those without a `Clone` bound can't clone.
+#[test]
+fn every_sort_reports_its_moves() {
+    let data: Vec<_> = (0..32).rev().collect();
+
+    for sorter in Registry::<Counted<i32>>::comparison_sorts().iter() {
+        let (_, report) = instrument_sort(data.clone(), |d| sorter.sort(d));
+        assert!(report.comparisons > 0, "{}", sorter.name());
+        assert!(report.swaps + report.writes > 0, "{}", sorter.name());
+    }
+
+    for sorter in Registry::<Counted<i32>>::non_cloning_sorts().iter() {
+        let (_, report) = instrument_sort(data.clone(), |d| sorter.sort(d));
+        assert_eq!(report.clones, 0, "{}", sorter.name());
+    }
+}
+
+#[test]
+fn insertion_sort_on_reversed_input() {
+    let n = 100;
+    let data: Vec<_> = (0..n).rev().collect();
+
+    let (_, report) = instrument_sort(data, insertion_sort);
+
+    // Every element has to pass every smaller one, one comparison and swap at a time.
+    assert_eq!(
+        report,
+        Report {
+            comparisons: n * (n - 1) / 2,
+            swaps: n * (n - 1) / 2,
+            writes: 0,
+            clones: 0,
+        }
+    );
+}
+
+#[test]
+fn merge_sort_on_reversed_input() {
+    let n = 64;
+    let lg_n = 6;
+    let data: Vec<_> = (0..n).rev().collect();
+
+    let pure = MergeSortConfig {
+        insertion_threshold: 1,
+    };
+    let (_, report) = instrument_sort(data, |d| merge_sort_with_config(d, pure));
+
+    // Each of the `lg n` levels of merging copies all elements out and writes them back in.
+    assert!(report.comparisons <= n * lg_n);
+    assert_eq!(report.writes, n * lg_n);
+    assert_eq!(report.clones, n * lg_n);
+    assert_eq!(report.swaps, 0);
+}
+
+#[test]
+fn bottom_up_merge_sort_on_reversed_input() {
+    let n = 64;
+    let lg_n = 6;
+    let data: Vec<_> = (0..n).rev().collect();
+
+    let (_, report) = instrument_sort(data, bottom_up_merge_sort);
+
+    // The buffer is filled once; after that, each of the `lg n` passes moves every element between
+    // the slice and the buffer, writing one slot of the slice either way. An even number of passes
+    // leaves the result in the slice.
+    assert!(report.comparisons <= n * lg_n);
+    assert_eq!(report.writes, n * lg_n);
+    assert_eq!(report.clones, n);
+    assert_eq!(report.swaps, 0);
+}
+
+#[test]
+fn parallel_merge_sort_counts_all_threads() {
+    let n = 64;
+    let data: Vec<_> = (0..n as i32).rev().collect();
+
+    let config = ParallelMergeSortConfig {
+        grain_size: 1,
+        threads: 4,
+    };
+    let (sorted, report) = instrument_sort(data, |d| parallel_merge_sort_with_config(d, config));
+
+    // With a grain size of one, all comparisons happen in merges spread over the threads, and the
+    // only clones are those filling the buffer.
+    assert!(sorted.is_sorted());
+    assert!(report.comparisons >= comparison_lower_bound(n));
+    assert_eq!(report.clones, n);
+    assert!(report.writes > 0);
+}
+
+#[test]
+fn selection_sort_swaps() {
+    // Selection sort swaps the minimum of the rest into every position but the last, even if it's
+    // there already.
+    let data = vec![2, 3, 1, 5, 4];
+    let (_, report) = instrument_sort(data, selection_sort);
+
+    assert_eq!(report.swaps, 4);
+}
+
+#[quickcheck]
+fn non_comparison_sorts(data: Vec<u16>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable();
+    let n = data.len();
+
+    // Distributing the elements takes at most one swap per element, and no comparisons.
+    let moved_by_swaps =
+        |report: Report| report.comparisons == 0 && report.swaps < n.max(1) && report.writes == 0;
+
+    let (radix, radix_report) = instrument_sort(data.clone(), radix_sort);
+    let (counting, counting_report) = instrument_sort(data.clone(), |d| {
+        counting_sort(d, 1 << u16::BITS, |x: &Counted<u16>| usize::from(**x))
+    });
+
+    let fractions: Vec<_> = data.iter().map(|&x| f64::from(x) / 65536.0).collect();
+    let (buckets, _) = instrument_sort(fractions, bucket_sort);
+
+    radix == expected
+        && counting == expected
+        && buckets.is_sorted()
+        && moved_by_swaps(radix_report)
+        && moved_by_swaps(counting_report)
+}
+
+#[test]
+fn dropped_clone_is_not_a_write() {
+    let (_, report) = instrument_sort(vec![1, 2, 3], |d| drop(d[0].clone()));
+
+    assert_eq!(
+        report,
+        Report {
+            clones: 1,
+            ..Report::default()
+        }
+    );
+}
+
+#[test]
+fn assignment_is_a_write() {
+    let (data, report) = instrument_sort(vec![1, 2, 3], |d| d[2] = d[0].clone());
+
+    assert_eq!(data, [1, 2, 1]);
+    assert_eq!(
+        report,
+        Report {
+            writes: 1,
+            clones: 1,
+            ..Report::default()
+        }
+    );
+}
+
+#[quickcheck]
+fn linear_search_comparisons(v: ElementOf<i32>) -> bool {
+    let (index, report) = instrument_search(&v.data, v.element, linear_search);
+    index.is_some_and(|i| report.comparisons == i + 1)
+}
+
+#[quickcheck]
+fn binary_search_comparisons(v: ElementOf<i32>) -> bool {
+    let mut data = v.data;
+    data.sort_unstable();
+
+    let (index, report) = instrument_search(&data, v.element, binary_search);
+
+    // One comparison for each halving, and a final one to check for equality.
+    let halvings = data.len().next_power_of_two().ilog2() as usize;
+    index.is_some() && report.comparisons <= halvings + 1 && report.writes == 0
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let (nothing, report) = instrument_sort(Vec::<i32>::new(), merge_sort);
+    assert!(nothing.is_empty());
+    assert_eq!(report, Report::default());
+}
@@ -1,7 +1,13 @@
+pub mod incomparable;
+pub mod instrumentation;
 pub mod matrix_multiplication;
 pub mod maximum_subarray;
+pub mod priority_queue;
 pub mod searching;
+pub mod selection;
 pub mod sorting;
+pub mod sorting_networks;
+pub mod young_tableau;
 
 mod util;
 
@@ -41,6 +41,12 @@ impl<T> Matrix<T> {
     pub fn data(&self) -> &[T] {
         &self.data
     }
+
+    /// Swap the elements at indices `a` and `b`.
+    pub fn swap(&mut self, a: MatrixIndex, b: MatrixIndex) {
+        let MatrixDimensions { columns, .. } = self.dimensions;
+        self.data.swap(a.x + a.y * columns, b.x + b.y * columns);
+    }
 }
 
 impl<T> Matrix<T>
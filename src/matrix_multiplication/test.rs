@@ -58,3 +58,13 @@ fn from_iterator(width: u8, height: u8) -> TestResult {
             },
     )
 }
+
+#[test]
+fn swap() {
+    let dimensions = MatrixDimensions { columns: 2, rows: 2 };
+    let mut matrix = Matrix::from_data(dimensions, vec![1, 2, 3, 4]);
+
+    matrix.swap(MatrixIndex { x: 1, y: 0 }, MatrixIndex { x: 0, y: 1 });
+
+    assert_eq!(matrix.data(), &[1, 3, 2, 4]);
+}
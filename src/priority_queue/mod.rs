@@ -0,0 +1,322 @@
+/*! # Chapter 6.5 - Priority Queues
+
+ A priority queue maintains a set of elements, each with an associated key, and gives quick access
+ to the element with the largest (max-priority queue) or smallest (min-priority queue) key. The
+ implementation follows the book in storing the elements in a binary heap, so that INSERT,
+ EXTRACT-MAX and INCREASE-KEY run in `O(lg n)` time and MAXIMUM in `O(1)`.
+
+ The book glosses over one detail: to change the key of an element, one has to know where in the
+ heap the element currently resides, which changes every time the heap is rearranged. We solve
+ this by handing out a `Handle` for every inserted element, and keeping a table mapping handles to
+ heap positions up to date on every swap. Slots of the table are reused once their element has left
+ the queue; a generation counter makes sure that stale handles are not mistaken for new ones.
+*/
+use std::marker::PhantomData;
+
+use crate::sorting::heap_sort::{left, parent, right};
+
+#[cfg(test)]
+mod test;
+
+/// The order in which a priority queue hands out its elements.
+pub trait Order {
+    /// Whether an element keyed `a` belongs closer to the root of the heap than one keyed `b`.
+    fn precedes<K: PartialOrd>(a: &K, b: &K) -> bool;
+}
+
+/// Largest keys first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Max;
+
+/// Smallest keys first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Min;
+
+impl Order for Max {
+    fn precedes<K: PartialOrd>(a: &K, b: &K) -> bool {
+        a > b
+    }
+}
+
+impl Order for Min {
+    fn precedes<K: PartialOrd>(a: &K, b: &K) -> bool {
+        a < b
+    }
+}
+
+pub type MaxPriorityQueue<K, V> = PriorityQueue<K, V, Max>;
+pub type MinPriorityQueue<K, V> = PriorityQueue<K, V, Min>;
+
+/// A stable reference to an element of a priority queue, valid until the element is removed.
+///
+/// Handles are only meaningful for the queue that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    slot: usize,
+    generation: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    slot: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Slot {
+    position: Option<usize>,
+    generation: usize,
+}
+
+/// A priority queue of values `V` keyed by `K`, with the ordering given by `O`.
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<K, V, O> {
+    heap: Vec<Entry<K, V>>,
+    slots: Vec<Slot>,
+    free_slots: Vec<usize>,
+    order: PhantomData<O>,
+}
+
+impl<K, V, O> Default for PriorityQueue<K, V, O>
+where
+    K: PartialOrd,
+    O: Order,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, O> PriorityQueue<K, V, O>
+where
+    K: PartialOrd,
+    O: Order,
+{
+    /// Construct an empty priority queue.
+    pub fn new() -> Self {
+        PriorityQueue {
+            heap: Vec::new(),
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            order: PhantomData,
+        }
+    }
+
+    /// The number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Whether there are no elements in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Whether the element referred to by `handle` is still in the queue.
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.position(handle).is_some()
+    }
+
+    /// The key and value of the element referred to by `handle`, if it is still in the queue.
+    pub fn get(&self, handle: Handle) -> Option<(&K, &V)> {
+        let entry = &self.heap[self.position(handle)?];
+        Some((&entry.key, &entry.value))
+    }
+
+    /// Add `value` with priority `key` to the queue, returning a handle to it.
+    pub fn insert(&mut self, key: K, value: V) -> Handle {
+        let position = self.heap.len();
+
+        let slot = match self.free_slots.pop() {
+            Some(slot) => {
+                self.slots[slot].position = Some(position);
+                slot
+            }
+            None => {
+                self.slots.push(Slot {
+                    position: Some(position),
+                    generation: 0,
+                });
+                self.slots.len() - 1
+            }
+        };
+
+        self.heap.push(Entry { key, value, slot });
+        self.sift_up(position);
+
+        Handle {
+            slot,
+            generation: self.slots[slot].generation,
+        }
+    }
+
+    /// The element with the highest priority, without removing it.
+    pub fn peek(&self) -> Option<(&K, &V)> {
+        self.heap.first().map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Remove and return the element with the highest priority.
+    pub fn extract(&mut self) -> Option<(K, V)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        Some(self.remove_at(0))
+    }
+
+    /// Remove the element referred to by `handle` from the queue, wherever it is in the heap.
+    pub fn remove(&mut self, handle: Handle) -> Option<(K, V)> {
+        let position = self.position(handle)?;
+        Some(self.remove_at(position))
+    }
+
+    /// Give the element referred to by `handle` the new priority `key`, returning the old one.
+    ///
+    /// Unlike `increase_key` and `decrease_key`, the new key may lie in either direction.
+    ///
+    /// ## Panics
+    /// This function panics if the element is no longer in the queue.
+    pub fn change_key(&mut self, handle: Handle, key: K) -> K {
+        let position = self
+            .position(handle)
+            .unwrap_or_else(|| panic!("Handle {:?} does not refer to a queued element", handle));
+
+        let old_key = std::mem::replace(&mut self.heap[position].key, key);
+        if O::precedes(&self.heap[position].key, &old_key) {
+            self.sift_up(position);
+        } else {
+            self.sift_down(position);
+        }
+
+        old_key
+    }
+
+    fn position(&self, handle: Handle) -> Option<usize> {
+        let slot = self.slots.get(handle.slot)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+
+        slot.position
+    }
+
+    // Take the entry at `position` out of the heap by swapping it with the last entry, which then
+    // has to find its new place: it might have to go either way, as it comes from another subtree.
+    fn remove_at(&mut self, position: usize) -> (K, V) {
+        let last = self.heap.len() - 1;
+        self.swap(position, last);
+
+        let entry = self.heap.pop().unwrap();
+        let slot = &mut self.slots[entry.slot];
+        slot.position = None;
+        slot.generation += 1;
+        self.free_slots.push(entry.slot);
+
+        if position < self.heap.len() {
+            self.sift_up(position);
+            self.sift_down(position);
+        }
+
+        (entry.key, entry.value)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.slots[self.heap[i].slot].position = Some(i);
+        self.slots[self.heap[j].slot].position = Some(j);
+    }
+
+    // The floating-up half of HEAP-INCREASE-KEY.
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 && O::precedes(&self.heap[i].key, &self.heap[parent(i)].key) {
+            self.swap(i, parent(i));
+            i = parent(i);
+        }
+    }
+
+    // MAX-HEAPIFY, generalized to either order.
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let l = left(i);
+            let r = right(i);
+
+            let mut first = i;
+            if l < self.heap.len() && O::precedes(&self.heap[l].key, &self.heap[first].key) {
+                first = l;
+            }
+            if r < self.heap.len() && O::precedes(&self.heap[r].key, &self.heap[first].key) {
+                first = r;
+            }
+
+            if first == i {
+                return;
+            }
+
+            self.swap(i, first);
+            i = first;
+        }
+    }
+}
+
+impl<K, V> PriorityQueue<K, V, Max>
+where
+    K: PartialOrd,
+{
+    /// MAXIMUM: The element with the largest key.
+    pub fn maximum(&self) -> Option<(&K, &V)> {
+        self.peek()
+    }
+
+    /// EXTRACT-MAX: Remove and return the element with the largest key.
+    pub fn extract_max(&mut self) -> Option<(K, V)> {
+        self.extract()
+    }
+
+    /// INCREASE-KEY: Raise the key of the element referred to by `handle` to `key`.
+    ///
+    /// ## Panics
+    /// This function panics if the element is no longer in the queue, or if `key` is smaller than
+    /// its current key.
+    pub fn increase_key(&mut self, handle: Handle, key: K) {
+        if let Some((current, _)) = self.get(handle) {
+            assert!(
+                !Min::precedes(&key, current),
+                "New key must not be smaller than the current key!"
+            );
+        }
+
+        self.change_key(handle, key);
+    }
+}
+
+impl<K, V> PriorityQueue<K, V, Min>
+where
+    K: PartialOrd,
+{
+    /// MINIMUM: The element with the smallest key.
+    pub fn minimum(&self) -> Option<(&K, &V)> {
+        self.peek()
+    }
+
+    /// EXTRACT-MIN: Remove and return the element with the smallest key.
+    pub fn extract_min(&mut self) -> Option<(K, V)> {
+        self.extract()
+    }
+
+    /// DECREASE-KEY: Lower the key of the element referred to by `handle` to `key`.
+    ///
+    /// ## Panics
+    /// This function panics if the element is no longer in the queue, or if `key` is larger than
+    /// its current key.
+    pub fn decrease_key(&mut self, handle: Handle, key: K) {
+        if let Some((current, _)) = self.get(handle) {
+            assert!(
+                !Max::precedes(&key, current),
+                "New key must not be larger than the current key!"
+            );
+        }
+
+        self.change_key(handle, key);
+    }
+}
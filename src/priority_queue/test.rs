@@ -0,0 +1,132 @@
+use quickcheck_macros::quickcheck;
+
+use super::{MaxPriorityQueue, MinPriorityQueue};
+
+#[quickcheck]
+fn max_extraction_order(data: Vec<i32>) -> bool {
+    let mut queue = MaxPriorityQueue::new();
+    for &d in &data {
+        queue.insert(d, ());
+    }
+
+    let mut expected = data;
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+
+    expected
+        .into_iter()
+        .all(|e| queue.extract_max().map(|(key, _)| key) == Some(e))
+        && queue.is_empty()
+}
+
+#[quickcheck]
+fn min_extraction_order(data: Vec<i32>) -> bool {
+    let mut queue = MinPriorityQueue::new();
+    for &d in &data {
+        queue.insert(d, ());
+    }
+
+    let mut expected = data;
+    expected.sort_unstable();
+
+    expected
+        .into_iter()
+        .all(|e| queue.extract_min().map(|(key, _)| key) == Some(e))
+        && queue.is_empty()
+}
+
+#[quickcheck]
+fn handles_follow_elements(data: Vec<i32>, changes: Vec<(usize, i32)>) -> bool {
+    let mut queue = MaxPriorityQueue::new();
+    let handles: Vec<_> = data
+        .iter()
+        .enumerate()
+        .map(|(i, &d)| queue.insert(d, i))
+        .collect();
+
+    let mut keys = data;
+    for (index, key) in changes {
+        if keys.is_empty() {
+            break;
+        }
+
+        let index = index % keys.len();
+        queue.change_key(handles[index], key);
+        keys[index] = key;
+    }
+
+    let lookups_agree = handles
+        .iter()
+        .enumerate()
+        .all(|(i, &h)| queue.get(h) == Some((&keys[i], &i)));
+
+    let mut previous = None;
+    while let Some((key, value)) = queue.extract() {
+        if keys[value] != key || previous.is_some_and(|p| p < key) {
+            return false;
+        }
+        previous = Some(key);
+    }
+
+    lookups_agree && handles.into_iter().all(|h| !queue.contains(h))
+}
+
+#[quickcheck]
+fn removal(data: Vec<i32>, index: usize) -> bool {
+    if data.is_empty() {
+        return true;
+    }
+
+    let mut queue = MinPriorityQueue::new();
+    let handles: Vec<_> = data.iter().map(|&d| queue.insert(d, ())).collect();
+
+    let index = index % data.len();
+    let removed = queue.remove(handles[index]);
+
+    let mut expected = data.clone();
+    expected.remove(index);
+    expected.sort_unstable();
+
+    let mut extracted = Vec::new();
+    while let Some((key, _)) = queue.extract_min() {
+        extracted.push(key);
+    }
+
+    removed == Some((data[index], ())) && extracted == expected
+}
+
+// Edge case(s)
+
+#[test]
+fn empty() {
+    let mut queue: MaxPriorityQueue<i32, ()> = MaxPriorityQueue::new();
+    assert!(queue.maximum().is_none());
+    assert!(queue.extract_max().is_none());
+}
+
+#[test]
+fn stale_handles() {
+    let mut queue = MinPriorityQueue::new();
+    let first = queue.insert(1, "first");
+    queue.extract_min();
+
+    // The second element reuses the slot of the first one.
+    let second = queue.insert(2, "second");
+    assert!(queue.get(first).is_none());
+    assert_eq!(queue.get(second), Some((&2, &"second")));
+}
+
+#[test]
+#[should_panic]
+fn increasing_to_smaller_key() {
+    let mut queue = MaxPriorityQueue::new();
+    let handle = queue.insert(5, ());
+    queue.increase_key(handle, 4);
+}
+
+#[test]
+#[should_panic]
+fn decreasing_to_larger_key() {
+    let mut queue = MinPriorityQueue::new();
+    let handle = queue.insert(5, ());
+    queue.decrease_key(handle, 6);
+}
@@ -9,7 +9,12 @@
  Binary search is based on the observation that for a sorted input array, one can improve on the
  `O(n)` of linear search by dividing-and-conquering the array in halves, by simply comparing against
  the middle element to see which side you have to search in.
+
+ Rather than just any index of `v`, it is often more useful to know where `v` would have to be
+ inserted to keep `A` sorted: `lower_bound` finds the first such position, before all elements
+ equal to `v`, and `upper_bound` the last one, after all of them.
 */
+use std::cmp::Ordering;
 use std::ops::{Range, RangeBounds};
 
 use crate::util::slice;
@@ -51,3 +56,82 @@ where
         None
     }
 }
+
+/// Like `binary_search`, with `compare` telling how an element relates to the value searched for.
+/// `data` has to be sorted consistently with `compare`. Returns the index of some element for which
+/// `compare` yields `Equal`, if there is one.
+pub fn binary_search_by<T, F>(data: &[T], mut compare: F) -> Option<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let (mut start, mut end) = (0, data.len());
+
+    while start < end {
+        let middle = start + (end - start) / 2;
+
+        match compare(&data[middle]) {
+            Ordering::Less => start = middle + 1,
+            Ordering::Greater => end = middle,
+            Ordering::Equal => return Some(middle),
+        }
+    }
+
+    None
+}
+
+/// Index of the first element of the sorted `data` that is not less than `value`, or `data.len()`
+/// if there is no such element.
+pub fn lower_bound<T>(data: &[T], value: &T) -> usize
+where
+    T: PartialOrd,
+{
+    partition_point(data, |x| x < value)
+}
+
+/// Index of the first element of the sorted `data` that is greater than `value`, or `data.len()`
+/// if there is no such element.
+pub fn upper_bound<T>(data: &[T], value: &T) -> usize
+where
+    T: PartialOrd,
+{
+    partition_point(data, |x| value.partial_cmp(x) != Some(Ordering::Less))
+}
+
+/// Like `lower_bound`, with `compare` telling how an element relates to the value searched for.
+/// `data` has to be sorted consistently with `compare`.
+pub fn lower_bound_by<T, F>(data: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    partition_point(data, |x| compare(x) == Ordering::Less)
+}
+
+/// Like `upper_bound`, with `compare` telling how an element relates to the value searched for.
+/// `data` has to be sorted consistently with `compare`.
+pub fn upper_bound_by<T, F>(data: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    partition_point(data, |x| compare(x) != Ordering::Greater)
+}
+
+// Index of the first element of `data` for which `predicate` fails, given that it holds for all
+// elements of some prefix of `data` and for none after that.
+fn partition_point<T, P>(data: &[T], mut predicate: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let (mut start, mut end) = (0, data.len());
+
+    while start < end {
+        let middle = start + (end - start) / 2;
+
+        if predicate(&data[middle]) {
+            start = middle + 1;
+        } else {
+            end = middle;
+        }
+    }
+
+    start
+}
@@ -1,4 +1,6 @@
-use super::binary_search;
+use super::{
+    binary_search, binary_search_by, lower_bound, lower_bound_by, upper_bound, upper_bound_by,
+};
 use quickcheck_macros::quickcheck;
 
 use crate::testing::vector::{ElementOf, NoElementOf};
@@ -17,6 +19,45 @@ fn doesnt_find_unmember(v: NoElementOf<i32>) -> bool {
     binary_search(&data, v.element).is_none()
 }
 
+#[quickcheck]
+fn bounds(data: Vec<u8>, value: u8) -> bool {
+    let mut data = data;
+    data.sort_unstable();
+
+    let lower = lower_bound(&data, &value);
+    let upper = upper_bound(&data, &value);
+
+    lower <= upper
+        && data[..lower].iter().all(|&x| x < value)
+        && data[lower..upper].iter().all(|&x| x == value)
+        && data[upper..].iter().all(|&x| x > value)
+}
+
+#[quickcheck]
+fn bounds_by(data: Vec<u8>, value: u8) -> bool {
+    let mut data = data;
+    data.sort_unstable_by(|a, b| b.cmp(a));
+
+    // Compare in reverse, as the data is in descending order.
+    let lower = lower_bound_by(&data, |x| value.cmp(x));
+    let upper = upper_bound_by(&data, |x| value.cmp(x));
+
+    data.reverse();
+    let n = data.len();
+    lower == n - upper_bound(&data, &value) && upper == n - lower_bound(&data, &value)
+}
+
+#[quickcheck]
+fn search_by(data: Vec<u8>, value: u8) -> bool {
+    let mut data = data;
+    data.sort_unstable_by(|a, b| b.cmp(a));
+
+    match binary_search_by(&data, |x| value.cmp(x)) {
+        Some(i) => data[i] == value,
+        None => !data.contains(&value),
+    }
+}
+
 
 // Edge cases
 
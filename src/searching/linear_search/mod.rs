@@ -11,6 +11,8 @@
  whatever direction you prefer) and check if your value is among the elements encountered on the
  way.
 */
+use std::cmp::Ordering;
+
 #[cfg(test)]
 mod test;
 
@@ -26,3 +28,12 @@ where
 
     None
 }
+
+/// Like `linear_search`, with `compare` telling how an element relates to the value searched for.
+/// Returns the index of the first element for which `compare` yields `Equal`, if there is one.
+pub fn linear_search_by<T, F>(data: &[T], mut compare: F) -> Option<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    data.iter().position(|x| compare(x) == Ordering::Equal)
+}
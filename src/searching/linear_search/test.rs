@@ -1,4 +1,4 @@
-use super::linear_search;
+use super::{linear_search, linear_search_by};
 use quickcheck_macros::quickcheck;
 
 use crate::testing::vector::{ElementOf, NoElementOf};
@@ -13,6 +13,12 @@ fn doesnt_find_unmember(v: NoElementOf<i32>) -> bool {
     linear_search(&v.data, v.element).is_none()
 }
 
+#[quickcheck]
+fn search_by(data: Vec<(u8, u8)>, key: u8) -> bool {
+    let index = linear_search_by(&data, |&(k, _)| k.cmp(&key));
+    index == data.iter().position(|&(k, _)| k == key)
+}
+
 // Edge cases
 
 #[test]
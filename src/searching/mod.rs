@@ -1,2 +1,3 @@
 pub mod linear_search;
 pub mod binary_search;
+pub mod young_tableau_search;
@@ -0,0 +1,46 @@
+/*! # Problem 6-3 - Young Tableau Search
+
+ **Input:** An `m * n` matrix `A` whose rows and columns are each sorted, and a value `v`
+
+ **Output:** An index `(x, y)` such that `v == A[x, y]` or `NIL` if `v` does not appear in `A`
+
+ **Time complexity:** `O(m + n)`
+
+ Binary search does not carry over to two dimensions very well, but a matrix whose rows and columns
+ are sorted (such as a Young tableau) has a different handy property: its top right element is the
+ smallest element of its column and the largest of its row. Comparing against it thus lets us
+ discard either the whole column or the whole row, so that we walk a staircase from the top right
+ to the bottom left corner, taking at most `m + n - 1` steps.
+*/
+use std::cmp::Ordering;
+
+use crate::matrix_multiplication::{Matrix, MatrixDimensions, MatrixIndex};
+
+#[cfg(test)]
+mod test;
+
+pub fn young_tableau_search<T>(data: &Matrix<T>, value: T) -> Option<MatrixIndex>
+where
+    T: PartialOrd,
+{
+    let MatrixDimensions { columns, rows } = data.dimensions();
+    if columns == 0 || rows == 0 {
+        return None;
+    }
+
+    let mut index = MatrixIndex {
+        x: columns - 1,
+        y: 0,
+    };
+
+    loop {
+        match data[index].partial_cmp(&value) {
+            Some(Ordering::Equal) => return Some(index),
+            // Everything below in this column is at least as large, so the column is done for.
+            Some(Ordering::Greater) if index.x > 0 => index.x -= 1,
+            // Everything to the left in this row is at most as large, so the row is done for.
+            Some(Ordering::Less) if index.y < rows - 1 => index.y += 1,
+            _ => return None,
+        }
+    }
+}
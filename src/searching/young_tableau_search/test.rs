@@ -0,0 +1,61 @@
+use super::young_tableau_search;
+use quickcheck_macros::quickcheck;
+
+use crate::{
+    matrix_multiplication::{Matrix, MatrixDimensions, MatrixIndex},
+    testing::vector::{ElementOf, NoElementOf},
+};
+
+// Filling a matrix with sorted data row by row sorts both its rows and columns.
+fn sorted_matrix(mut data: Vec<i32>) -> Matrix<i32> {
+    data.sort_unstable();
+
+    let columns = (data.len() as f64).sqrt().ceil() as usize;
+    let rows = data.len().div_ceil(columns);
+    data.resize(columns * rows, i32::MAX);
+
+    Matrix::from_data(MatrixDimensions { columns, rows }, data)
+}
+
+#[quickcheck]
+fn finds_members(v: ElementOf<i32>) -> bool {
+    let matrix = sorted_matrix(v.data);
+    matches!(young_tableau_search(&matrix, v.element), Some(i) if matrix[i] == v.element)
+}
+
+#[quickcheck]
+fn doesnt_find_unmember(v: NoElementOf<i32>) -> bool {
+    if v.element == i32::MAX {
+        return true;
+    }
+
+    let matrix = sorted_matrix(v.data);
+    young_tableau_search(&matrix, v.element).is_none()
+}
+
+// Edge cases
+
+#[test]
+fn no_data() {
+    let nothing: Matrix<i32> = Matrix::new(MatrixDimensions {
+        columns: 0,
+        rows: 0,
+    });
+    assert_eq!(young_tableau_search(&nothing, 0), None);
+}
+
+#[test]
+fn single_column() {
+    let data = Matrix::from_data(
+        MatrixDimensions {
+            columns: 1,
+            rows: 3,
+        },
+        vec![1, 5, 9],
+    );
+    assert_eq!(
+        young_tableau_search(&data, 9),
+        Some(MatrixIndex { x: 0, y: 2 })
+    );
+    assert_eq!(young_tableau_search(&data, 4), None);
+}
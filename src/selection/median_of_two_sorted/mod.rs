@@ -0,0 +1,59 @@
+/*! # Exercise 9.3-8 - Median of Two Sorted Arrays
+
+ **Input:** Two sequences `X` and `Y` of `m` and `n` elements of a partial order, each in sorted
+ order
+
+ **Output:** The lower median of all `m + n` elements
+
+ **Time complexity:** `O(lg min(m, n))`
+
+ Merging the two sequences up to the middle would take linear time. But we don't need to actually
+ merge them, we just need to know how many of the `h = ⌈(m + n) / 2⌉` smallest elements come from
+ `X`. Say that's `i`, so the other `h - i` come from `Y`. Then the first `i` elements of `X` and the
+ first `h - i` elements of `Y` are exactly the `h` smallest elements if and only if neither `x_i`
+ nor `y_{h - i}` would have to be swapped for a larger one from the other sequence, and the median
+ is the larger of the two. Whether some `i` is too small or too large can be told by comparing
+ these elements, so that a binary search over `i` finds the right one. The book's exercise has
+ `m = n`, but this works just as well for sequences of different lengths.
+*/
+
+#[cfg(test)]
+mod test;
+
+/// Find the lower median of the elements of the sorted slices `x` and `y`, or `None` if both are
+/// empty.
+pub fn median_of_two_sorted<'a, T>(x: &'a [T], y: &'a [T]) -> Option<&'a T>
+where
+    T: PartialOrd,
+{
+    // Searching the shorter slice bounds the number of steps.
+    let (x, y) = if x.len() <= y.len() { (x, y) } else { (y, x) };
+    let (m, n) = (x.len(), y.len());
+    if n == 0 {
+        return None;
+    }
+
+    let h = (m + n).div_ceil(2);
+
+    // Binary search for the smallest `i` such that `x[i]` doesn't belong to the smallest `h`
+    // elements, or all of `x` does.
+    let (mut low, mut high) = (h.saturating_sub(n), m.min(h));
+    while low < high {
+        let i = low + (high - low) / 2;
+        if x[i] < y[h - i - 1] {
+            low = i + 1;
+        } else {
+            high = i;
+        }
+    }
+
+    let i = low;
+    let j = h - i;
+    match (
+        i.checked_sub(1).map(|i| &x[i]),
+        j.checked_sub(1).map(|j| &y[j]),
+    ) {
+        (Some(a), Some(b)) => Some(if b > a { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
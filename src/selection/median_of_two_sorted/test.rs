@@ -0,0 +1,39 @@
+use quickcheck_macros::quickcheck;
+
+use super::median_of_two_sorted;
+
+#[quickcheck]
+fn finds_median(mut x: Vec<i32>, mut y: Vec<i32>) -> bool {
+    x.sort_unstable();
+    y.sort_unstable();
+
+    let mut all = [x.clone(), y.clone()].concat();
+    all.sort_unstable();
+    let expected = all.len().checked_sub(1).map(|n| all[n / 2]);
+
+    median_of_two_sorted(&x, &y).copied() == expected
+}
+
+#[test]
+fn equal_lengths() {
+    let x = [1, 3, 5, 7];
+    let y = [2, 4, 6, 8];
+    assert_eq!(median_of_two_sorted(&x, &y), Some(&4));
+}
+
+#[test]
+fn disjoint_ranges() {
+    let x = [1, 2, 3];
+    let y = [10, 20, 30, 40, 50];
+    assert_eq!(median_of_two_sorted(&x, &y), Some(&10));
+    assert_eq!(median_of_two_sorted(&y, &x), Some(&10));
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let nothing: [i32; 0] = [];
+    assert_eq!(median_of_two_sorted(&nothing, &nothing), None);
+    assert_eq!(median_of_two_sorted(&nothing, &[1]), Some(&1));
+}
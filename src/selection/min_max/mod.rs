@@ -0,0 +1,54 @@
+/*! # Chapter 9.1 - Minimum and Maximum
+
+ **Input:** A sequence of `n` elements of a partial order `A = (a_1, a_2, ..., a_n)`
+
+ **Output:** The smallest and the largest element of `A`
+
+ **Time complexity:** `O(n)`, with at most `3 ⌊n / 2⌋` comparisons
+
+ Finding the minimum and the maximum separately takes `n - 1` comparisons each. Doing both at once,
+ we can save a quarter of them by processing the elements in pairs: first compare the two elements
+ of a pair with each other, then only the smaller one with the current minimum, and only the larger
+ one with the current maximum. That's three comparisons for every two elements.
+*/
+
+#[cfg(test)]
+mod test;
+
+/// Find the smallest and the largest element of `data` at once, or `None` if `data` is empty.
+pub fn min_max<T>(data: &[T]) -> Option<(&T, &T)>
+where
+    T: PartialOrd,
+{
+    // With an odd number of elements, the first one starts out as both minimum and maximum;
+    // otherwise, the first pair sets them.
+    let (mut min, mut max, rest) = match data {
+        [] => return None,
+        [first, rest @ ..] if data.len() % 2 == 1 => (first, first, rest),
+        [first, second, rest @ ..] => {
+            if second < first {
+                (second, first, rest)
+            } else {
+                (first, second, rest)
+            }
+        }
+        _ => unreachable!(),
+    };
+
+    for pair in rest.chunks_exact(2) {
+        let (small, large) = if pair[1] < pair[0] {
+            (&pair[1], &pair[0])
+        } else {
+            (&pair[0], &pair[1])
+        };
+
+        if small < min {
+            min = small;
+        }
+        if large > max {
+            max = large;
+        }
+    }
+
+    Some((min, max))
+}
@@ -0,0 +1,29 @@
+use quickcheck_macros::quickcheck;
+
+use super::min_max;
+use crate::instrumentation::instrument_search;
+
+#[quickcheck]
+fn finds_extremes(data: Vec<i32>) -> bool {
+    let expected = data.iter().min().zip(data.iter().max());
+    min_max(&data) == expected
+}
+
+#[quickcheck]
+fn comparisons(data: Vec<i32>) -> bool {
+    let (_, report) = instrument_search(&data, 0, |data, _| min_max(data).is_some());
+    report.comparisons <= 3 * (data.len() / 2)
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let nothing: Vec<i32> = Vec::new();
+    assert_eq!(min_max(&nothing), None);
+}
+
+#[test]
+fn only_element() {
+    assert_eq!(min_max(&[12]), Some((&12, &12)));
+}
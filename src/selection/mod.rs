@@ -0,0 +1,25 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::{sorting::quick_sort::three_way_partition_by, util::slice};
+
+pub mod median_of_two_sorted;
+pub mod min_max;
+pub mod partial_sort;
+pub mod quantiles;
+pub mod randomized_select;
+pub mod second_smallest;
+pub mod select;
+pub mod weighted_median;
+
+// Partition `data` around the element at index `pivot`, as in `three_way_partition`. Grouping the
+// elements equal to the pivot keeps the selection algorithms from degrading to quadratic time on
+// inputs with many duplicates, and lets them stop early once the rank falls into that group.
+fn partition_around<T, F>(data: &mut [T], pivot: usize, compare: &mut F) -> Range<usize>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let r = data.len() - 1;
+    slice::swap(data, pivot, r);
+    three_way_partition_by(data, compare)
+}
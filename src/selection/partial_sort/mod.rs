@@ -0,0 +1,113 @@
+/*! # Problem 9-1 - Largest i Numbers in Sorted Order
+
+ **Input:** A sequence of `n` elements of a partial order `A = (a_1, a_2, ..., a_n)` and a number
+ `k`
+
+ **Output:** The `k` smallest (or largest) elements of `A` in sorted order
+
+ **Time complexity:** `O(n + k lg k)`
+
+ Sorting everything and taking the first `k` elements takes `O(n lg n)` time, which is a waste if
+ `k` is much smaller than `n`. Instead, we SELECT the element of rank `k - 1`, which leaves the `k`
+ smallest elements at the front of the slice in linear time, and then sort just those `k`. Of the
+ approaches the book discusses, this is the one with the best asymptotic running time.
+
+ The front is sorted with the three-way quicksort, so the `O(k lg k)` is an expected bound; the
+ selection is linear in the worst case. The rest of the slice is left in no particular order.
+ Requesting the largest elements just sorts in reverse.
+*/
+use std::cmp::Ordering;
+
+use crate::{
+    selection::select::select_by, sorting::quick_sort::three_way_quick_sort_by,
+    util::cmp::partial_compare,
+};
+
+#[cfg(test)]
+mod test;
+
+/// Rearrange `data` such that `data[..k]` holds its `k` smallest elements in sorted order. If `k`
+/// exceeds the length of `data`, all of it is sorted.
+pub fn partial_sort<T>(data: &mut [T], k: usize)
+where
+    T: PartialOrd,
+{
+    partial_sort_by(data, k, partial_compare);
+}
+
+/// Like `partial_sort`, but by the keys `key` extracts from the elements.
+pub fn partial_sort_by_key<T, K, F>(data: &mut [T], k: usize, mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    partial_sort_by(data, k, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Like `partial_sort`, but in the order defined by `compare`.
+pub fn partial_sort_by<T, F>(data: &mut [T], k: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let k = k.min(data.len());
+    if k == 0 {
+        return;
+    }
+
+    select_by(data, k - 1, &mut compare);
+    three_way_quick_sort_by(&mut data[..k], compare);
+}
+
+/// The `k` smallest elements of `data`, in ascending order. This rearranges `data` as
+/// `partial_sort` does.
+pub fn smallest_k<T>(data: &mut [T], k: usize) -> &[T]
+where
+    T: PartialOrd,
+{
+    smallest_k_by(data, k, partial_compare)
+}
+
+/// Like `smallest_k`, but by the keys `key` extracts from the elements.
+pub fn smallest_k_by_key<T, K, F>(data: &mut [T], k: usize, mut key: F) -> &[T]
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    smallest_k_by(data, k, |a, b| partial_compare(&key(a), &key(b)))
+}
+
+/// Like `smallest_k`, but in the order defined by `compare`.
+pub fn smallest_k_by<T, F>(data: &mut [T], k: usize, compare: F) -> &[T]
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    partial_sort_by(data, k, compare);
+    &data[..k.min(data.len())]
+}
+
+/// The `k` largest elements of `data`, in descending order. This rearranges `data` like
+/// `partial_sort` does, but in reverse.
+pub fn largest_k<T>(data: &mut [T], k: usize) -> &[T]
+where
+    T: PartialOrd,
+{
+    largest_k_by(data, k, partial_compare)
+}
+
+/// Like `largest_k`, but by the keys `key` extracts from the elements.
+pub fn largest_k_by_key<T, K, F>(data: &mut [T], k: usize, mut key: F) -> &[T]
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    largest_k_by(data, k, |a, b| partial_compare(&key(a), &key(b)))
+}
+
+/// Like `largest_k`, but in the order defined by `compare`, so the elements come out in the
+/// reverse of that order.
+pub fn largest_k_by<T, F>(data: &mut [T], k: usize, mut compare: F) -> &[T]
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    smallest_k_by(data, k, |a, b| compare(b, a))
+}
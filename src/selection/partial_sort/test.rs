@@ -0,0 +1,75 @@
+use quickcheck_macros::quickcheck;
+
+use super::{
+    largest_k, largest_k_by_key, partial_sort, partial_sort_by, smallest_k, smallest_k_by_key,
+};
+
+#[quickcheck]
+fn sorts_prefix(data: Vec<i32>, k: usize) -> bool {
+    let k = k % (data.len() + 2);
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    let mut data = data;
+    partial_sort(&mut data, k);
+
+    let k = k.min(data.len());
+    let mut rest = data[k..].to_vec();
+    rest.sort_unstable();
+
+    data[..k] == expected[..k] && rest == expected[k..]
+}
+
+#[quickcheck]
+fn descending_order(data: Vec<i32>, k: usize) -> bool {
+    let k = k % (data.len() + 1);
+    let mut expected = data.clone();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut data = data;
+    partial_sort_by(&mut data, k, |a, b| b.cmp(a));
+    data[..k] == expected[..k]
+}
+
+#[quickcheck]
+fn smallest_and_largest(data: Vec<i32>, k: usize) -> bool {
+    let k = k % (data.len() + 1);
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    let mut data = data;
+    let smallest = smallest_k(&mut data, k).to_vec();
+    let largest = largest_k(&mut data, k).to_vec();
+
+    smallest == expected[..k] && largest.iter().eq(expected.iter().rev().take(k))
+}
+
+#[test]
+fn by_key() {
+    let mut rows = vec![("b", 20), ("a", 40), ("d", 10), ("c", 30), ("e", 50)];
+
+    assert_eq!(
+        largest_k_by_key(&mut rows, 2, |&(_, value)| value),
+        [("e", 50), ("a", 40)]
+    );
+    assert_eq!(
+        smallest_k_by_key(&mut rows, 2, |&(name, _)| name),
+        [("a", 40), ("b", 20)]
+    );
+}
+
+#[test]
+fn top_100() {
+    let mut data: Vec<_> = (0..100_000).map(|i| (i * 7919) % 100_000).collect();
+    let top: Vec<_> = (99_900..100_000).rev().collect();
+    assert_eq!(largest_k(&mut data, 100), &top[..]);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    partial_sort(&mut nothing, 3);
+    assert!(smallest_k(&mut nothing, 3).is_empty());
+}
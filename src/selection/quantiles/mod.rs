@@ -0,0 +1,76 @@
+/*! # Exercise 9.3-6 - k-th Quantiles
+
+ **Input:** A sequence of `n` elements of a partial order `A = (a_1, a_2, ..., a_n)` and a number
+ `k`
+
+ **Output:** The `k - 1` order statistics that divide the sorted `A` into `k` sets of (almost) equal
+ size
+
+ **Time complexity:** `O(n lg k)`
+
+ The `j`-th of the `k`-th quantiles is the element of rank `⌈j n / k⌉ - 1`, counting from zero; for
+ `k = 2`, that's the lower median, and for `k = 100`, we get percentiles. Selecting each of them
+ separately takes `O(n k)` time. Instead, we select the middle one of the ranks we're looking for
+ and partition around it, and then recurse into both sides, each with the half of the ranks that
+ falls into it. Every level of this recursion takes `O(n)` time in total, and there are `⌈lg k⌉`
+ levels.
+
+ Selection leaves `data` partitioned around the selected element, and the recursion only touches
+ the parts on either side of it. So, in the end, every quantile sits at the index of its rank.
+*/
+use crate::selection::select::select;
+
+#[cfg(test)]
+mod test;
+
+/// The ranks of the `k`-th quantiles in a sorted sequence of `n` elements, counting from zero.
+pub fn quantile_ranks(n: usize, k: usize) -> Vec<usize> {
+    (1..k)
+        .map(|j| (j * n).div_ceil(k).saturating_sub(1))
+        .collect()
+}
+
+/// Find the `k`-th quantiles of `data`, the elements whose ranks are given by `quantile_ranks`.
+/// This rearranges `data`, moving each quantile to the index of its rank.
+///
+/// ## Panics
+/// This function panics if `k` is zero, or `data` is empty while `k > 1`.
+pub fn k_quantiles<T>(data: &mut [T], k: usize) -> Vec<&T>
+where
+    T: PartialOrd,
+{
+    assert!(k > 0, "Cannot divide into zero sets");
+    assert!(
+        !data.is_empty() || k == 1,
+        "Cannot find quantiles of an empty slice"
+    );
+
+    let ranks = quantile_ranks(data.len(), k);
+
+    // With fewer elements than sets, some ranks occur more than once.
+    let mut distinct = ranks.clone();
+    distinct.dedup();
+    select_ranks(data, &distinct);
+
+    ranks.into_iter().map(|r| &data[r]).collect()
+}
+
+// Select all the elements of the given increasing `ranks`.
+fn select_ranks<T>(data: &mut [T], ranks: &[usize])
+where
+    T: PartialOrd,
+{
+    if ranks.is_empty() {
+        return;
+    }
+
+    let middle = ranks.len() / 2;
+    let r = ranks[middle];
+    select(data, r);
+
+    let (left, right) = data.split_at_mut(r);
+    let right_ranks: Vec<_> = ranks[middle + 1..].iter().map(|&s| s - r - 1).collect();
+
+    select_ranks(left, &ranks[..middle]);
+    select_ranks(&mut right[1..], &right_ranks);
+}
@@ -0,0 +1,61 @@
+use quickcheck_macros::quickcheck;
+
+use super::{k_quantiles, quantile_ranks};
+
+#[quickcheck]
+fn finds_quantiles(data: Vec<i32>, k: u8) -> bool {
+    let k = usize::from(k % 20) + 1;
+    if data.is_empty() {
+        return true;
+    }
+
+    let mut sorted = data.clone();
+    sorted.sort_unstable();
+    let expected: Vec<_> = quantile_ranks(data.len(), k)
+        .into_iter()
+        .map(|r| sorted[r])
+        .collect();
+
+    let mut data = data;
+    let quantiles: Vec<_> = k_quantiles(&mut data, k).into_iter().copied().collect();
+    quantiles == expected
+}
+
+#[test]
+fn ranks() {
+    assert_eq!(quantile_ranks(10, 2), vec![4]);
+    assert_eq!(quantile_ranks(9, 2), vec![4]);
+    assert_eq!(quantile_ranks(12, 4), vec![2, 5, 8]);
+    assert_eq!(quantile_ranks(2, 4), vec![0, 0, 1]);
+}
+
+#[test]
+fn percentiles() {
+    let mut data: Vec<_> = (1..=1000).rev().collect();
+    let percentiles = k_quantiles(&mut data, 100);
+
+    assert_eq!(percentiles.len(), 99);
+    assert_eq!(*percentiles[49], 500);
+    assert_eq!(*percentiles[98], 990);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    assert!(k_quantiles(&mut nothing, 1).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn no_data_quantiles() {
+    let mut nothing: Vec<i32> = Vec::new();
+    k_quantiles(&mut nothing, 2);
+}
+
+#[test]
+#[should_panic]
+fn zero_sets() {
+    k_quantiles(&mut [1, 2, 3], 0);
+}
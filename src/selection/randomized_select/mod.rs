@@ -0,0 +1,80 @@
+/*! # Chapter 9.2 - Selection in Expected Linear Time
+
+ **Input:** A sequence of `n` elements of a partial order `A = (a_1, a_2, ..., a_n)` and a rank
+ `i`, counting from zero
+
+ **Output:** The element `x` of `A` that is larger than exactly `i` other elements, with ties
+ resolved as if `A` was sorted
+
+ **Time complexity:** `O(n)` expected, `O(n^2)` in the worst case
+
+ Sorting finds the `i`-th smallest element in `O(n lg n)` time, but does a lot of unnecessary work
+ to do so. RANDOMIZED-SELECT partitions the input around a random pivot, just like randomized
+ quicksort, but then only recurses into the side that contains the element we're looking for. The
+ expected sizes of the subproblems shrink geometrically, giving a linear expected running time.
+
+ Afterwards, the selected element sits at index `i`, all elements before it are at most as large,
+ and all elements after it at least as large, so e.g. `data[..i]` holds the `i` smallest elements.
+*/
+use std::cmp::Ordering;
+
+use super::partition_around;
+use crate::util::{cmp::partial_compare, random::Random};
+
+#[cfg(test)]
+mod test;
+
+/// RANDOMIZED-SELECT: Find the `i`-th smallest element of `data`, counting from zero, moving it to
+/// index `i` and partitioning `data` around it.
+///
+/// ## Panics
+/// This function panics if `i` is out of bounds.
+pub fn randomized_select<T>(data: &mut [T], i: usize) -> &T
+where
+    T: PartialOrd,
+{
+    randomized_select_by(data, i, partial_compare)
+}
+
+/// Like `randomized_select`, but by the keys `key` extracts from the elements.
+///
+/// ## Panics
+/// This function panics if `i` is out of bounds.
+pub fn randomized_select_by_key<T, K, F>(data: &mut [T], i: usize, mut key: F) -> &T
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    randomized_select_by(data, i, |a, b| partial_compare(&key(a), &key(b)))
+}
+
+/// Like `randomized_select`, but in the order defined by `compare`.
+///
+/// ## Panics
+/// This function panics if `i` is out of bounds.
+pub fn randomized_select_by<T, F>(data: &mut [T], i: usize, mut compare: F) -> &T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(i < data.len(), "Rank {} out of bounds", i);
+
+    let mut random = Random::new();
+    let mut data = data;
+    let mut i = i;
+
+    // The recursion of the book is a tail call, which we turn into a loop.
+    loop {
+        let pivot = random.range(0..data.len());
+        let equal = partition_around(data, pivot, &mut compare);
+
+        let rest = data;
+        if i < equal.start {
+            data = &mut rest[..equal.start];
+        } else if i >= equal.end {
+            i -= equal.end;
+            data = &mut rest[equal.end..];
+        } else {
+            return &rest[i];
+        }
+    }
+}
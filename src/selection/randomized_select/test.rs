@@ -0,0 +1,60 @@
+use quickcheck_macros::quickcheck;
+
+use super::{randomized_select, randomized_select_by, randomized_select_by_key};
+
+#[quickcheck]
+fn selects(data: Vec<i32>, i: usize) -> bool {
+    if data.is_empty() {
+        return true;
+    }
+
+    let i = i % data.len();
+    let mut sorted = data.clone();
+    sorted.sort_unstable();
+
+    let mut data = data;
+    let selected = *randomized_select(&mut data, i);
+
+    selected == sorted[i]
+        && data[i] == selected
+        && data[..i].iter().all(|&x| x <= selected)
+        && data[i..].iter().all(|&x| x >= selected)
+}
+
+#[quickcheck]
+fn selects_by(data: Vec<(u8, u8)>, i: usize) -> bool {
+    if data.is_empty() {
+        return true;
+    }
+
+    let i = i % data.len();
+    let mut sorted = data.clone();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    let (mut descending, mut by_key) = (data.clone(), data);
+    let selected = *randomized_select_by(&mut descending, i, |a, b| b.cmp(a));
+    let keyed = randomized_select_by_key(&mut by_key, i, |&(key, _)| std::cmp::Reverse(key)).0;
+
+    selected == sorted[i] && keyed == sorted[i].0
+}
+
+#[test]
+fn many_duplicates() {
+    let mut data = vec![7; 100_000];
+    assert_eq!(*randomized_select(&mut data, 50_000), 7);
+}
+
+// Edge case(s)
+
+#[test]
+#[should_panic]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    randomized_select(&mut nothing, 0);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    randomized_select(&mut [1, 2, 3], 3);
+}
@@ -0,0 +1,56 @@
+/*! # Exercise 9.1-1 - Second Smallest Element
+
+ **Input:** A sequence of `n >= 2` elements of a partial order `A = (a_1, a_2, ..., a_n)`
+
+ **Output:** The second smallest element of `A`
+
+ **Time complexity:** `O(n)`, with at most `n + ⌈lg n⌉ - 2` comparisons
+
+ The minimum can be found with `n - 1` comparisons in a knockout tournament: pair up the elements,
+ let the smaller one of each pair advance to the next round, and repeat until a single winner is
+ left. The second smallest element can only ever have lost against the winner, who played at most
+ `⌈lg n⌉` rounds. So we remember whom each element beat, and find the smallest of the at most
+ `⌈lg n⌉` elements the winner beat with another `⌈lg n⌉ - 1` comparisons.
+*/
+
+#[cfg(test)]
+mod test;
+
+/// Find the second smallest element of `data`, or `None` if it has fewer than two elements. If the
+/// minimum occurs more than once, it is also the second smallest.
+pub fn second_smallest<T>(data: &[T]) -> Option<&T>
+where
+    T: PartialOrd,
+{
+    if data.len() < 2 {
+        return None;
+    }
+
+    // The indices of the elements each element has beaten so far.
+    let mut beaten: Vec<Vec<usize>> = vec![Vec::new(); data.len()];
+    let mut round: Vec<usize> = (0..data.len()).collect();
+
+    while round.len() > 1 {
+        // An odd one out advances without playing.
+        let next = round
+            .chunks(2)
+            .map(|pair| match *pair {
+                [a, b] => {
+                    let (winner, loser) = if data[b] < data[a] { (b, a) } else { (a, b) };
+                    beaten[winner].push(loser);
+                    winner
+                }
+                [a] => a,
+                _ => unreachable!(),
+            })
+            .collect();
+
+        round = next;
+    }
+
+    let winner = round[0];
+    beaten[winner]
+        .iter()
+        .map(|&i| &data[i])
+        .reduce(|a, b| if b < a { b } else { a })
+}
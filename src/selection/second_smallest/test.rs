@@ -0,0 +1,36 @@
+use quickcheck_macros::quickcheck;
+
+use super::second_smallest;
+use crate::instrumentation::instrument_search;
+
+#[quickcheck]
+fn finds_second_smallest(data: Vec<i32>) -> bool {
+    let mut sorted = data.clone();
+    sorted.sort_unstable();
+    second_smallest(&data) == sorted.get(1)
+}
+
+#[quickcheck]
+fn comparisons(data: Vec<i32>) -> bool {
+    if data.len() < 2 {
+        return true;
+    }
+
+    let n = data.len();
+    let lg_n = n.next_power_of_two().ilog2() as usize;
+    let (_, report) = instrument_search(&data, 0, |data, _| second_smallest(data).is_some());
+    report.comparisons <= n + lg_n - 2
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let nothing: Vec<i32> = Vec::new();
+    assert_eq!(second_smallest(&nothing), None);
+}
+
+#[test]
+fn only_element() {
+    assert_eq!(second_smallest(&[12]), None);
+}
@@ -0,0 +1,113 @@
+/*! # Chapter 9.3 - Selection in Worst-Case Linear Time
+
+ **Input:** A sequence of `n` elements of a partial order `A = (a_1, a_2, ..., a_n)` and a rank
+ `i`, counting from zero
+
+ **Output:** The element `x` of `A` that is larger than exactly `i` other elements, with ties
+ resolved as if `A` was sorted
+
+ **Time complexity:** `O(n)`
+
+ RANDOMIZED-SELECT can be unlucky with its pivots. SELECT rules that out by choosing the pivot
+ deterministically, such that a constant fraction of the elements is guaranteed to end up on either
+ side. It divides the input into groups of five, finds the median of each group by sorting it, and
+ then recursively selects the median `x` of these `⌊n / 5⌋` medians. At least half of the medians
+ are at most as large as `x`, and so are three elements of each of their groups. Hence at least
+ `3 ⌊n / 10⌋` elements are no larger than `x`, and likewise no smaller, so that the recursion after
+ partitioning around `x` is on at most about `7 n / 10` elements. Together with the recursion on
+ `n / 5` medians, this solves to `O(n)`.
+
+ Like the book's fourth edition, we only look at complete groups, and leave the remaining at most
+ four elements out of the median finding. The guarantees stay the same. The medians are gathered
+ at the front of the slice, so that the recursion can work in place.
+
+ Afterwards, the selected element sits at index `i`, all elements before it are at most as large,
+ and all elements after it at least as large, just as with `randomized_select`.
+*/
+use std::cmp::Ordering;
+
+use super::partition_around;
+use crate::{
+    sorting::insertion_sort::insertion_sort_by,
+    util::{cmp::partial_compare, slice},
+};
+
+#[cfg(test)]
+mod test;
+
+// Slices up to this length are simply sorted.
+const GROUP_SIZE: usize = 5;
+
+/// SELECT: Find the `i`-th smallest element of `data`, counting from zero, moving it to index `i`
+/// and partitioning `data` around it.
+///
+/// ## Panics
+/// This function panics if `i` is out of bounds.
+pub fn select<T>(data: &mut [T], i: usize) -> &T
+where
+    T: PartialOrd,
+{
+    select_by(data, i, partial_compare)
+}
+
+/// Like `select`, but by the keys `key` extracts from the elements.
+///
+/// ## Panics
+/// This function panics if `i` is out of bounds.
+pub fn select_by_key<T, K, F>(data: &mut [T], i: usize, mut key: F) -> &T
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    select_by(data, i, |a, b| partial_compare(&key(a), &key(b)))
+}
+
+/// Like `select`, but in the order defined by `compare`.
+///
+/// ## Panics
+/// This function panics if `i` is out of bounds.
+pub fn select_by<T, F>(data: &mut [T], i: usize, mut compare: F) -> &T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    select_recursion(data, i, &mut compare)
+}
+
+fn select_recursion<'a, T, F>(data: &'a mut [T], i: usize, compare: &mut F) -> &'a T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(i < data.len(), "Rank {} out of bounds", i);
+
+    let mut data = data;
+    let mut i = i;
+
+    loop {
+        if data.len() <= GROUP_SIZE {
+            insertion_sort_by(data, &mut *compare);
+            return &data[i];
+        }
+
+        // Sort each group, and move its median to the front.
+        let groups = data.len() / GROUP_SIZE;
+        for g in 0..groups {
+            let group = g * GROUP_SIZE;
+            insertion_sort_by(&mut data[group..group + GROUP_SIZE], &mut *compare);
+            slice::swap(data, g, group + GROUP_SIZE / 2);
+        }
+
+        let pivot = groups / 2;
+        select_recursion(&mut data[..groups], pivot, compare);
+        let equal = partition_around(data, pivot, compare);
+
+        let rest = data;
+        if i < equal.start {
+            data = &mut rest[..equal.start];
+        } else if i >= equal.end {
+            i -= equal.end;
+            data = &mut rest[equal.end..];
+        } else {
+            return &rest[i];
+        }
+    }
+}
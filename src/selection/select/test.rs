@@ -0,0 +1,76 @@
+use quickcheck_macros::quickcheck;
+
+use super::{select, select_by, select_by_key};
+use crate::instrumentation::instrument_search;
+
+#[quickcheck]
+fn selects(data: Vec<i32>, i: usize) -> bool {
+    if data.is_empty() {
+        return true;
+    }
+
+    let i = i % data.len();
+    let mut sorted = data.clone();
+    sorted.sort_unstable();
+
+    let mut data = data;
+    let selected = *select(&mut data, i);
+
+    selected == sorted[i]
+        && data[i] == selected
+        && data[..i].iter().all(|&x| x <= selected)
+        && data[i..].iter().all(|&x| x >= selected)
+}
+
+#[test]
+fn linear_comparisons() {
+    // Sorted and reversed inputs are worst cases for naive pivot choices.
+    for data in [(0..10_000).collect::<Vec<_>>(), (0..10_000).rev().collect()] {
+        let n = data.len();
+        let (median, report) = instrument_search(&data, 0, |data, _| {
+            let mut data = data.to_vec();
+            *select(&mut data, n / 2).value()
+        });
+
+        assert_eq!(median, 5_000);
+        assert!(report.comparisons < 30 * n);
+    }
+}
+
+#[quickcheck]
+fn selects_by(data: Vec<(u8, u8)>, i: usize) -> bool {
+    if data.is_empty() {
+        return true;
+    }
+
+    let i = i % data.len();
+    let mut sorted = data.clone();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    let (mut descending, mut by_key) = (data.clone(), data);
+    let selected = *select_by(&mut descending, i, |a, b| b.cmp(a));
+    let keyed = select_by_key(&mut by_key, i, |&(key, _)| std::cmp::Reverse(key)).0;
+
+    selected == sorted[i] && keyed == sorted[i].0
+}
+
+#[test]
+fn many_duplicates() {
+    let mut data = vec![7; 100_000];
+    assert_eq!(*select(&mut data, 50_000), 7);
+}
+
+// Edge case(s)
+
+#[test]
+#[should_panic]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    select(&mut nothing, 0);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    select(&mut [1, 2, 3], 3);
+}
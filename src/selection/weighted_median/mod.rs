@@ -0,0 +1,71 @@
+/*! # Problem 9-2 - Weighted Median
+
+ **Input:** A sequence of `n` elements of a partial order `A = (a_1, a_2, ..., a_n)` with
+ nonnegative weights `w_1, w_2, ..., w_n` summing up to `W`
+
+ **Output:** The (lower) weighted median of `A`, i.e. the element `a_k` such that the elements
+ before it in sorted order weigh less than `W / 2`, while those up to and including it weigh at
+ least `W / 2`
+
+ **Time complexity:** `O(n)`
+
+ With all weights equal, this is just the ordinary lower median. In general, the weighted median
+ minimizes the weighted sum of distances `Σ w_i |a_i - x|` over all `x`, which makes it the best
+ location for a facility serving points on a line, e.g. a post office on a street.
+
+ Sorting and summing up the weights finds it in `O(n lg n)` time. To get down to linear time, we
+ use SELECT to find the ordinary median and partition around it, and sum up the weights of the
+ lower half. That tells us which half the weighted median lies in, and we continue in that half
+ only, remembering the weight of everything we've discarded to the left. The halves shrink
+ geometrically, so the total time is linear.
+*/
+use crate::selection::select::select;
+
+#[cfg(test)]
+mod test;
+
+/// Find the weighted median of `data`, with the weights given by `weight`, or `None` if `data` is
+/// empty. This rearranges `data`.
+///
+/// ## Panics
+/// This function panics if any of the weights is negative or NaN.
+pub fn weighted_median<T, F>(data: &mut [T], mut weight: F) -> Option<&T>
+where
+    T: PartialOrd,
+    F: FnMut(&T) -> f64,
+{
+    let total: f64 = data
+        .iter()
+        .map(|x| {
+            let w = weight(x);
+            assert!(w >= 0.0, "Invalid weight {}", w);
+            w
+        })
+        .sum();
+    let half = total / 2.0;
+
+    let mut data = data;
+    // The weight of all the elements discarded to the left of `data` so far.
+    let mut below = 0.0;
+
+    while data.len() > 1 {
+        let m = data.len() / 2;
+        select(data, m);
+
+        let before = below + data[..m].iter().map(&mut weight).sum::<f64>();
+        let through = before + weight(&data[m]);
+
+        let rest = data;
+        if m > 0 && before >= half {
+            data = &mut rest[..m];
+        } else if through >= half || m + 1 == rest.len() {
+            // Rounding errors in the sums might make us miss the median otherwise.
+            return Some(&rest[m]);
+        } else {
+            below = through;
+            data = &mut rest[m + 1..];
+        }
+    }
+
+    data.first()
+}
@@ -0,0 +1,78 @@
+use quickcheck_macros::quickcheck;
+
+use super::weighted_median;
+
+// Sort, and take the first element at which the running sum of weights reaches half the total.
+fn naive_weighted_median(data: &[(i32, u8)]) -> Option<i32> {
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable();
+
+    let half = sorted.iter().map(|&(_, w)| f64::from(w)).sum::<f64>() / 2.0;
+    let mut sum = 0.0;
+    sorted
+        .into_iter()
+        .find(|&(_, w)| {
+            sum += f64::from(w);
+            sum >= half
+        })
+        .map(|(x, _)| x)
+}
+
+#[quickcheck]
+fn finds_weighted_median(data: Vec<(i32, u8)>) -> bool {
+    let expected = naive_weighted_median(&data);
+
+    // Compare by value only, the weights just come along.
+    let mut values: Vec<_> = data.iter().map(|&(x, w)| Weighted(x, w)).collect();
+    let median = weighted_median(&mut values, |v| f64::from(v.1)).map(|v| v.0);
+
+    median == expected
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Weighted(i32, u8);
+
+impl PartialEq for Weighted {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialOrd for Weighted {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+#[test]
+fn equal_weights() {
+    let mut data = [5, 1, 4, 2, 3, 6];
+    assert_eq!(weighted_median(&mut data, |_| 1.0), Some(&3));
+}
+
+#[test]
+fn heavy_element() {
+    let mut data = [5, 1, 4, 2, 3];
+    let weight = |&x: &i32| if x == 5 { 10.0 } else { 1.0 };
+    assert_eq!(weighted_median(&mut data, weight), Some(&5));
+}
+
+#[test]
+#[should_panic]
+fn negative_weight() {
+    weighted_median(&mut [1, 2, 3], |&x| f64::from(x) - 2.0);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    assert_eq!(weighted_median(&mut nothing, |_| 1.0), None);
+}
+
+#[test]
+fn zero_weights() {
+    let mut data = [3, 1, 2];
+    assert_eq!(weighted_median(&mut data, |_| 0.0), Some(&1));
+}
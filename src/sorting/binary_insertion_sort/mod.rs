@@ -0,0 +1,56 @@
+/*! # Exercise 2.3-6 - Binary Insertion Sort
+
+ **Input:** A sequence of `n` elements of a partial order `(a_1, a_2, ..., a_n)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(n^2)`, with `O(n lg n)` comparisons
+
+ Insertion sort scans the already sorted prefix linearly to find where the next element belongs.
+ Since that prefix is sorted, binary search can find the spot in `O(lg n)` comparisons instead.
+ This does not improve the running time, as making room for the element still shifts `Θ(n)`
+ elements in the worst case, but shifting is cheap compared to, say, comparing long strings or
+ calling an elaborate comparator.
+
+ To keep the sort stable, each element has to go after all equal elements of the prefix, which is
+ exactly the position found by `upper_bound`.
+*/
+use std::cmp::Ordering;
+
+use crate::{
+    searching::binary_search::upper_bound_by,
+    util::{cmp::partial_compare, slice},
+};
+
+#[cfg(test)]
+mod test;
+
+pub fn binary_insertion_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    binary_insertion_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn binary_insertion_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    binary_insertion_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn binary_insertion_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for j in 1..data.len() {
+        let (sorted, rest) = data.split_at(j);
+        let i = upper_bound_by(sorted, |x| compare(x, &rest[0]));
+
+        slice::rotate_right(&mut data[i..=j], 1);
+    }
+}
@@ -0,0 +1,56 @@
+use quickcheck_macros::quickcheck;
+
+use super::{binary_insertion_sort, binary_insertion_sort_by, binary_insertion_sort_by_key};
+use crate::instrumentation::instrument_sort;
+
+#[quickcheck]
+fn sortedness(mut data: Vec<i32>) -> bool {
+    binary_insertion_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    binary_insertion_sort(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    binary_insertion_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn stability_by_key(mut data: Vec<(u8, u8)>) -> bool {
+    let mut data_copy = data.clone();
+    binary_insertion_sort_by_key(&mut data, |&(key, _)| key % 8);
+    data_copy.sort_by_key(|&(key, _)| key % 8);
+    data == data_copy
+}
+
+#[test]
+fn comparisons() {
+    let n: usize = 1000;
+    let data: Vec<_> = (0..n).rev().collect();
+
+    let (sorted, report) = instrument_sort(data, binary_insertion_sort);
+
+    // Inserting into a prefix of length `j` takes at most `⌈lg(j + 1)⌉` comparisons.
+    let bound: usize = (1..n)
+        .map(|j| (j + 1).next_power_of_two().ilog2() as usize)
+        .sum();
+    assert!(sorted.is_sorted());
+    assert!(report.comparisons <= bound);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    binary_insertion_sort(&mut nothing);
+    assert!(nothing == Vec::new());
+}
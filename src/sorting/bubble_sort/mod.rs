@@ -12,13 +12,34 @@
  sorting algorithms. It always runs in `O(n^2)` time, even in the best case, and does `O(n^2)` swaps
  in the worst case. Don't use it for anything serious.
 */
+use std::cmp::Ordering;
+
+use crate::util::{cmp::partial_compare, slice};
+
 #[cfg(test)]
 mod test;
 
-// This implementation is pretty much what is given in the book.
 pub fn bubble_sort<T>(data: &mut [T])
 where
     T: PartialOrd,
+{
+    bubble_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn bubble_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    bubble_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+// This implementation is pretty much what is given in the book.
+/// Sort `data` into the order defined by `compare`.
+pub fn bubble_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
 {
     if data.len() <= 1 {
         return;
@@ -26,8 +47,8 @@ where
 
     for i in 0..(data.len() - 1) {
         for j in ((i + 1)..data.len()).rev() {
-            if data[j] < data[j - 1] {
-                data.swap(j, j - 1)
+            if compare(&data[j], &data[j - 1]) == Ordering::Less {
+                slice::swap(data, j, j - 1)
             }
         }
     }
@@ -1,6 +1,6 @@
 use quickcheck_macros::quickcheck;
 
-use super::bubble_sort;
+use super::{bubble_sort, bubble_sort_by, bubble_sort_by_key};
 
 // If only `is_sorted` was already stabilized...
 fn is_sorted<T: PartialOrd>(data: &Vec<T>) -> bool {
@@ -31,6 +31,20 @@ fn sortedness_preservation(mut data: Vec<i32>) -> bool {
     data == data_copy
 }
 
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    bubble_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn stability_by_key(mut data: Vec<(u8, u8)>) -> bool {
+    let mut data_copy = data.clone();
+    bubble_sort_by_key(&mut data, |&(key, _)| key % 8);
+    data_copy.sort_by_key(|&(key, _)| key % 8);
+    data == data_copy
+}
+
 // Edge case(s)
 
 #[test]
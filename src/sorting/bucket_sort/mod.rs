@@ -0,0 +1,99 @@
+/*! # Chapter 8.4 - Bucket Sort
+
+ **Input:** A sequence of `n` numbers `(a_1, a_2, ..., a_n)` from the interval `[0, 1)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(n)` expected for uniformly distributed input, `O(n^2)` worst case
+
+ Bucket sort divides `[0, 1)` into `n` equally sized buckets, distributes the input over them, and
+ then sorts each bucket with insertion sort. If the input is drawn uniformly at random, every bucket
+ only receives a constant number of elements on average, so that sorting them is cheap and the
+ whole algorithm runs in expected linear time. If most elements end up in the same bucket, on the
+ other hand, we are left with insertion sort and its `O(n^2)`.
+
+ The book keeps the buckets as linked lists. We instead distribute the elements with counting sort
+ (using the bucket number as key), which leaves each bucket as a contiguous part of the input, ready
+ to be sorted in place. Values from other ranges can be sorted with `bucket_sort_by`, which takes a
+ function mapping them into `[0, 1)`.
+*/
+use crate::{
+    sorting::{counting_sort, insertion_sort::insertion_sort},
+    util::slice,
+};
+
+#[cfg(test)]
+mod test;
+
+/// A type whose values can be read as numbers from the unit interval `[0, 1)`.
+pub trait UnitInterval {
+    fn unit_value(&self) -> f64;
+}
+
+impl UnitInterval for f32 {
+    fn unit_value(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl UnitInterval for f64 {
+    fn unit_value(&self) -> f64 {
+        *self
+    }
+}
+
+/// Bucket sort `data`, whose elements all have to lie in `[0, 1)`.
+///
+/// ## Panics
+/// This function panics if an element lies outside of `[0, 1)`.
+pub fn bucket_sort<T>(data: &mut [T])
+where
+    T: PartialOrd + UnitInterval,
+{
+    bucket_sort_by(data, T::unit_value);
+}
+
+/// Bucket sort `data`, using `normalize` to map its elements into `[0, 1)`. For the result to be
+/// sorted, `normalize` has to be monotonic, i.e. never map a larger element to a smaller number.
+///
+/// ## Panics
+/// This function panics if `normalize` yields a number outside of `[0, 1)`.
+pub fn bucket_sort_by<T, F>(data: &mut [T], mut normalize: F)
+where
+    T: PartialOrd,
+    F: FnMut(&T) -> f64,
+{
+    let n = data.len();
+
+    let buckets: Vec<_> = data
+        .iter()
+        .map(|d| {
+            let x = normalize(d);
+            assert!(
+                (0.0..1.0).contains(&x),
+                "Normalized value {} lies outside of [0, 1)",
+                x
+            );
+
+            // Multiplying by `n` can round up to `n` itself for `x` just below 1.
+            ((n as f64 * x) as usize).min(n - 1)
+        })
+        .collect();
+
+    let mut destinations = counting_sort::destinations(&buckets, n);
+    slice::permute(data, &mut destinations);
+
+    // After distributing the elements, the buckets lie next to each other in ascending order, so
+    // we only need their sizes to find them.
+    let mut sizes = vec![0; n];
+    for bucket in buckets {
+        sizes[bucket] += 1;
+    }
+
+    let mut start = 0;
+    for size in sizes {
+        insertion_sort(&mut data[start..start + size]);
+        start += size;
+    }
+}
@@ -0,0 +1,65 @@
+use quickcheck_macros::quickcheck;
+
+use super::{bucket_sort, bucket_sort_by};
+
+fn unit_values(data: Vec<u32>) -> Vec<f64> {
+    data.into_iter()
+        .map(|d| d as f64 / (u32::MAX as f64 + 1.0))
+        .collect()
+}
+
+#[quickcheck]
+fn sortedness(data: Vec<u32>) -> bool {
+    let mut data = unit_values(data);
+    bucket_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(data: Vec<u32>) -> bool {
+    let mut data = unit_values(data);
+    let mut data_copy = data.clone();
+    bucket_sort(&mut data);
+    data_copy.sort_unstable_by(f64::total_cmp);
+    data == data_copy
+}
+
+#[quickcheck]
+fn single_precision(data: Vec<u16>) -> bool {
+    let mut data: Vec<_> = data.into_iter().map(|d| d as f32 / 65536.0).collect();
+    bucket_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn normalization(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+
+    let normalize = |&d: &i32| (d as f64 - i32::MIN as f64) / (u32::MAX as f64 + 1.0);
+    bucket_sort_by(&mut data, normalize);
+
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<f64> = Vec::new();
+    bucket_sort(&mut nothing);
+    assert!(nothing.is_empty());
+}
+
+#[test]
+fn one_bucket() {
+    let mut data = vec![0.5, 0.50003, 0.50001, 0.50002];
+    bucket_sort(&mut data);
+    assert_eq!(data, vec![0.5, 0.50001, 0.50002, 0.50003]);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    bucket_sort(&mut [0.5, 1.0]);
+}
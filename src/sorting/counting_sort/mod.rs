@@ -0,0 +1,91 @@
+/*! # Chapter 8.2 - Counting Sort
+
+ **Input:** A sequence of `n` elements `(a_1, a_2, ..., a_n)` with integer keys in `0..k`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `key(a'_1) <=
+ key(a'_2) <= ... <= key(a'_n)`, and elements with equal keys appear in their original order
+
+ **Time complexity:** `O(n + k)`
+
+ Counting sort is the first sorting algorithm in the book that doesn't compare elements, and hence
+ isn't bound by the `Ω(n lg n)` lower bound for comparison sorts. Instead, it counts how many
+ elements there are with each key, from which it can compute the exact place every element
+ belongs in. If `k` is in `O(n)`, this takes linear time. Counting sort is also stable, which makes
+ it the go-to subroutine for radix sort.
+
+ The book copies the elements into a separate output array. We want to be able to sort elements
+ that can't be cloned, so we instead compute every element's final position and then move the
+ elements there by swapping them in place. `counting_sort_into` and `counting_sort_to_vec` provide
+ the book's interface of writing into an output array on top of that.
+*/
+use crate::util::slice;
+
+#[cfg(test)]
+mod test;
+
+/// Stably sort `data` in place by `key`, which must map every element into `0..k`.
+///
+/// ## Panics
+/// This function panics if `key` yields a value of at least `k`.
+pub fn counting_sort<T, F>(data: &mut [T], k: usize, key: F)
+where
+    F: FnMut(&T) -> usize,
+{
+    let keys: Vec<_> = data.iter().map(key).collect();
+    let mut destinations = destinations(&keys, k);
+    slice::permute(data, &mut destinations);
+}
+
+/// Stably sort the elements of `data` by `key` into `output`, which is cleared first. Reusing
+/// `output` across calls avoids allocating a new buffer every time.
+///
+/// ## Panics
+/// This function panics if `key` yields a value of at least `k`.
+pub fn counting_sort_into<T, I, F>(data: I, k: usize, key: F, output: &mut Vec<T>)
+where
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> usize,
+{
+    output.clear();
+    output.extend(data);
+    counting_sort(output, k, key);
+}
+
+/// Stably sort the elements of `data` by `key` into a new `Vec`.
+///
+/// ## Panics
+/// This function panics if `key` yields a value of at least `k`.
+pub fn counting_sort_to_vec<T, I, F>(data: I, k: usize, key: F) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> usize,
+{
+    let mut output = Vec::new();
+    counting_sort_into(data, k, key, &mut output);
+    output
+}
+
+// This is the heart of the book's COUNTING-SORT, except that we write down where each element goes
+// instead of putting it there right away.
+pub(crate) fn destinations(keys: &[usize], k: usize) -> Vec<usize> {
+    let mut counts = vec![0; k];
+    for &key in keys {
+        assert!(key < k, "Key {} is out of range 0..{}", key, k);
+        counts[key] += 1;
+    }
+
+    // Now, `counts[i]` becomes the number of elements with a key of at most `i`...
+    for i in 1..k {
+        counts[i] += counts[i - 1];
+    }
+
+    // ... which is one past the position of the last such element. Going through the keys from
+    // the back assigns the positions in descending order, which is what makes the sort stable.
+    let mut destinations = vec![0; keys.len()];
+    for (j, &key) in keys.iter().enumerate().rev() {
+        counts[key] -= 1;
+        destinations[j] = counts[key];
+    }
+
+    destinations
+}
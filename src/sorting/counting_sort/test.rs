@@ -0,0 +1,72 @@
+use quickcheck_macros::quickcheck;
+
+use super::{counting_sort, counting_sort_into, counting_sort_to_vec};
+
+// A record that can only be moved, not cloned.
+#[derive(Debug, PartialEq, Eq)]
+struct Record {
+    key: u8,
+    index: usize,
+}
+
+fn records(keys: Vec<u8>) -> Vec<Record> {
+    keys.into_iter()
+        .enumerate()
+        .map(|(index, key)| Record { key, index })
+        .collect()
+}
+
+#[quickcheck]
+fn sortedness(mut data: Vec<u8>) -> bool {
+    counting_sort(&mut data, 256, |&d| d as usize);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<u8>) -> bool {
+    let mut data_copy = data.clone();
+    counting_sort(&mut data, 256, |&d| d as usize);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn stability(keys: Vec<u8>) -> bool {
+    let mut data = records(keys);
+    counting_sort(&mut data, 16, |r| (r.key % 16) as usize);
+
+    data.windows(2).all(|w| {
+        let (a, b) = (&w[0], &w[1]);
+        a.key % 16 < b.key % 16 || (a.key % 16 == b.key % 16 && a.index < b.index)
+    })
+}
+
+#[quickcheck]
+fn output_agreement(keys: Vec<u8>) -> bool {
+    let mut in_place = records(keys.clone());
+    counting_sort(&mut in_place, 256, |r| r.key as usize);
+
+    // Leftover elements in the buffer must not survive.
+    let mut buffer = records(vec![0; 3]);
+    counting_sort_into(records(keys.clone()), 256, |r| r.key as usize, &mut buffer);
+
+    let new = counting_sort_to_vec(records(keys), 256, |r| r.key as usize);
+
+    in_place == buffer && buffer == new
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<u8> = Vec::new();
+    counting_sort(&mut nothing, 0, |&d| d as usize);
+    assert!(nothing.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn key_out_of_range() {
+    let mut data = vec![1, 2, 3];
+    counting_sort(&mut data, 3, |&d| d);
+}
@@ -0,0 +1,383 @@
+/*! # External Merge Sort
+
+ **Input:** A sequence of `n` records of a partial order, read from a stream
+
+ **Output:** The records in sorted order, written to another stream
+
+ **Time complexity:** `O(n lg n)`, with `O(n)` bytes read and written `2 + p` times, where `p` is
+ the number of intermediate merge passes
+
+ All other sorting algorithms in this crate expect their input to fit into memory as a slice. When
+ it doesn't, we have to make do with a small part of it at a time. External merge sort reads as
+ many records as fit into a memory budget, sorts them with `merge_sort`, and writes the sorted run
+ out to a temporary file. Once the input is exhausted, a k-way merge of all runs produces the
+ output. It keeps just the smallest remaining record of each run in memory, in a min-priority
+ queue, and replaces every record it writes out with the next one from the same run.
+
+ Every run being merged needs an open file, and there may be more runs than the process is allowed
+ to open files. So at most `max_fan_in` runs are merged at a time: as long as there are more runs
+ than that, consecutive groups of them are merged into longer runs first, in as many passes as it
+ takes. Each pass reads and writes all records once more.
+
+ Ties between runs are broken in favour of the earlier run, which makes the sort stable. If the
+ whole input fits into the budget, no temporary files are created at all.
+
+ The records travel through files, so they need to be encoded to and decoded from bytes, which is
+ what the `Record` trait is for. It's implemented for the integer types, for `String` and
+ `Vec<u8>` with a length prefix, and for lines of text via the `Line` newtype.
+*/
+use std::{
+    env, fs,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    mem,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::merge_sort::merge_sort;
+use crate::priority_queue::MinPriorityQueue;
+
+#[cfg(test)]
+mod test;
+
+/// The memory budget used by `external_sort`, 64 MiB.
+pub const DEFAULT_MEMORY_BUDGET: usize = 64 << 20;
+
+/// The maximum number of runs merged at a time used by `external_sort`.
+pub const DEFAULT_MAX_FAN_IN: usize = 64;
+
+/// A value that can be written to and read back from a byte stream.
+pub trait Record: Sized {
+    /// Write the encoding of `self` to `writer`.
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Read the next record from `reader`, or `None` if the stream ended before it.
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>>;
+
+    /// The number of bytes of memory taken up by `self`, to be counted against the memory budget.
+    fn size(&self) -> usize {
+        mem::size_of::<Self>()
+    }
+}
+
+// Fill `buffer` from `reader`, returning `false` if the stream had already ended. A stream ending
+// in the middle of the buffer is an error.
+fn read_exact_or_end<R: BufRead>(reader: &mut R, buffer: &mut [u8]) -> io::Result<bool> {
+    if reader.fill_buf()?.is_empty() {
+        return Ok(false);
+    }
+
+    reader.read_exact(buffer)?;
+    Ok(true)
+}
+
+macro_rules! impl_record_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl Record for $t {
+                fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    writer.write_all(&self.to_be_bytes())
+                }
+
+                fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+                    let mut bytes = [0; mem::size_of::<$t>()];
+                    Ok(read_exact_or_end(reader, &mut bytes)?.then(|| <$t>::from_be_bytes(bytes)))
+                }
+            }
+        )*
+    };
+}
+
+impl_record_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Record for Vec<u8> {
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.len() as u64).write_to(writer)?;
+        writer.write_all(self)
+    }
+
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+        let Some(len) = u64::read_from(reader)? else {
+            return Ok(None);
+        };
+
+        let mut bytes = Vec::new();
+        reader.take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(Some(bytes))
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of::<Self>() + self.len()
+    }
+}
+
+impl Record for String {
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.len() as u64).write_to(writer)?;
+        writer.write_all(self.as_bytes())
+    }
+
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+        let Some(bytes) = Vec::read_from(reader)? else {
+            return Ok(None);
+        };
+
+        String::from_utf8(bytes)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of::<Self>() + self.len()
+    }
+}
+
+/// A line of text, encoded as itself followed by a newline. This allows sorting text files line by
+/// line.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Line(pub String);
+
+impl Record for Line {
+    /// Lines can't contain newlines themselves, trying to write one that does is an error.
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.0.contains('\n') {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "line contains a newline",
+            ));
+        }
+
+        writer.write_all(self.0.as_bytes())?;
+        writer.write_all(b"\n")
+    }
+
+    /// The last line doesn't need to end in a newline.
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        if line.ends_with('\n') {
+            line.pop();
+        }
+
+        Ok(Some(Line(line)))
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of::<Self>() + self.0.len()
+    }
+}
+
+/// Configuration of the external merge sort.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalSortConfig {
+    /// The number of bytes of records, as given by `Record::size`, to sort in memory at a time.
+    /// Every run contains at least one record, even if that exceeds the budget.
+    pub memory_budget: usize,
+    /// The maximum number of runs to merge at a time, and thus of files to have open at a time.
+    /// Values below 2 are treated as 2.
+    pub max_fan_in: usize,
+    /// The directory to store the sorted runs in.
+    pub temp_dir: PathBuf,
+}
+
+impl Default for ExternalSortConfig {
+    /// Use the default memory budget and fan-in and the system's temporary directory.
+    fn default() -> Self {
+        ExternalSortConfig {
+            memory_budget: DEFAULT_MEMORY_BUDGET,
+            max_fan_in: DEFAULT_MAX_FAN_IN,
+            temp_dir: env::temp_dir(),
+        }
+    }
+}
+
+/// Sort the records read from `input`, writing them to `output`.
+pub fn external_sort<T, R, W>(input: R, output: W) -> io::Result<()>
+where
+    T: Record + PartialOrd + Clone,
+    R: Read,
+    W: Write,
+{
+    external_sort_with_config::<T, R, W>(input, output, &ExternalSortConfig::default())
+}
+
+/// Sort the records read from `input`, writing them to `output`, with the memory budget, fan-in
+/// and temporary directory given by `config`.
+pub fn external_sort_with_config<T, R, W>(
+    input: R,
+    output: W,
+    config: &ExternalSortConfig,
+) -> io::Result<()>
+where
+    T: Record + PartialOrd + Clone,
+    R: Read,
+    W: Write,
+{
+    let mut input = BufReader::new(input);
+    let mut output = BufWriter::new(output);
+    let mut runs = Vec::new();
+
+    loop {
+        let (mut run, exhausted) = read_run::<T, _>(&mut input, config.memory_budget)?;
+        merge_sort(&mut run);
+
+        // Avoid the detour through the file system if everything fits into memory.
+        if exhausted && runs.is_empty() {
+            write_all(&run, &mut output)?;
+            break;
+        }
+
+        if !run.is_empty() {
+            runs.push(write_run(&config.temp_dir, |writer| {
+                write_all(&run, writer)
+            })?);
+        }
+
+        if exhausted {
+            let max_fan_in = config.max_fan_in.max(2);
+            while runs.len() > max_fan_in {
+                runs = merge_pass::<T>(runs, max_fan_in, &config.temp_dir)?;
+            }
+
+            merge_runs::<T, _>(&runs, &mut output)?;
+            break;
+        }
+    }
+
+    output.flush()
+}
+
+// Read records from `input` until they exceed `memory_budget`, also telling whether `input` is
+// exhausted.
+fn read_run<T, R>(input: &mut R, memory_budget: usize) -> io::Result<(Vec<T>, bool)>
+where
+    T: Record,
+    R: BufRead,
+{
+    let mut run = Vec::new();
+    let mut size = 0;
+
+    while run.is_empty() || size < memory_budget {
+        match T::read_from(input)? {
+            Some(record) => {
+                size += record.size();
+                run.push(record);
+            }
+            None => return Ok((run, true)),
+        }
+    }
+
+    Ok((run, false))
+}
+
+fn write_all<T, W>(records: &[T], output: &mut W) -> io::Result<()>
+where
+    T: Record,
+    W: Write,
+{
+    records
+        .iter()
+        .try_for_each(|record| record.write_to(output))
+}
+
+// Write a new run to a temporary file in `dir` with `write`.
+fn write_run<F>(dir: &Path, write: F) -> io::Result<TempFile>
+where
+    F: FnOnce(&mut BufWriter<File>) -> io::Result<()>,
+{
+    let (run_file, file) = TempFile::create(dir)?;
+    let mut writer = BufWriter::new(file);
+    write(&mut writer)?;
+    writer.flush()?;
+    Ok(run_file)
+}
+
+// Merge consecutive groups of `max_fan_in` runs into one run each. Keeping the groups in order
+// keeps the sort stable, and every group is deleted as soon as it has been merged.
+fn merge_pass<T>(
+    mut runs: Vec<TempFile>,
+    max_fan_in: usize,
+    dir: &Path,
+) -> io::Result<Vec<TempFile>>
+where
+    T: Record + PartialOrd,
+{
+    let mut merged = Vec::with_capacity(runs.len().div_ceil(max_fan_in));
+
+    while !runs.is_empty() {
+        let group: Vec<_> = runs.drain(..max_fan_in.min(runs.len())).collect();
+        merged.push(write_run(dir, |writer| merge_runs::<T, _>(&group, writer))?);
+    }
+
+    Ok(merged)
+}
+
+// Merge the sorted `runs` into `output`.
+fn merge_runs<T, W>(runs: &[TempFile], output: &mut W) -> io::Result<()>
+where
+    T: Record + PartialOrd,
+    W: Write,
+{
+    let mut readers = runs
+        .iter()
+        .map(|run| File::open(&run.path).map(BufReader::new))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    // Keying by the run index as well keeps equal records in the order of their runs.
+    let mut queue = MinPriorityQueue::new();
+    for (i, reader) in readers.iter_mut().enumerate() {
+        if let Some(record) = T::read_from(reader)? {
+            queue.insert((record, i), ());
+        }
+    }
+
+    while let Some(((record, i), ())) = queue.extract_min() {
+        record.write_to(output)?;
+
+        if let Some(next) = T::read_from(&mut readers[i])? {
+            queue.insert((next, i), ());
+        }
+    }
+
+    Ok(())
+}
+
+// A uniquely named file in a temporary directory, which is deleted again once dropped.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    // Create the file, returning it opened for writing along with the guard.
+    fn create(dir: &Path) -> io::Result<(TempFile, File)> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        loop {
+            let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let path = dir.join(format!("cl-rs-external-sort-{}-{}", process::id(), n));
+
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => return Ok((TempFile { path }, file)),
+                // Left over from an earlier process with the same ID.
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        // There's nothing sensible to do if this fails, and the OS will get to it eventually.
+        let _ = fs::remove_file(&self.path);
+    }
+}
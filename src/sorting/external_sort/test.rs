@@ -0,0 +1,203 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    process,
+};
+
+use quickcheck_macros::quickcheck;
+
+use super::{
+    external_sort, external_sort_with_config, ExternalSortConfig, Line, Record, DEFAULT_MAX_FAN_IN,
+};
+
+// A fresh temporary directory per test, so that we can check that all runs get cleaned up.
+fn config(name: &str, memory_budget: usize, max_fan_in: usize) -> ExternalSortConfig {
+    let temp_dir: PathBuf = env::temp_dir().join(format!(
+        "cl-rs-external-sort-test-{}-{}",
+        process::id(),
+        name
+    ));
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    ExternalSortConfig {
+        memory_budget,
+        max_fan_in,
+        temp_dir,
+    }
+}
+
+fn is_empty_dir(config: &ExternalSortConfig) -> bool {
+    let empty = fs::read_dir(&config.temp_dir).unwrap().next().is_none();
+    fs::remove_dir(&config.temp_dir).unwrap();
+    empty
+}
+
+fn encode<T: Record>(records: &[T]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for record in records {
+        record.write_to(&mut bytes).unwrap();
+    }
+    bytes
+}
+
+fn decode<T: Record>(mut bytes: &[u8]) -> Vec<T> {
+    let mut records = Vec::new();
+    while let Some(record) = T::read_from(&mut bytes).unwrap() {
+        records.push(record);
+    }
+    records
+}
+
+// A record that is ordered only by its key, to check stability.
+#[derive(Debug, Clone, PartialEq)]
+struct Keyed {
+    key: u8,
+    tag: u32,
+}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.key.partial_cmp(&other.key)
+    }
+}
+
+impl Record for Keyed {
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.key.write_to(writer)?;
+        self.tag.write_to(writer)
+    }
+
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+        let Some(key) = u8::read_from(reader)? else {
+            return Ok(None);
+        };
+        let tag = u32::read_from(reader)?.ok_or(io::ErrorKind::UnexpectedEof)?;
+        Ok(Some(Keyed { key, tag }))
+    }
+}
+
+#[quickcheck]
+fn sortedness_preservation(data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    data_copy.sort_unstable();
+
+    // A budget of a few records forces lots of runs.
+    let config = config(
+        "sortedness",
+        4 * std::mem::size_of::<i32>(),
+        DEFAULT_MAX_FAN_IN,
+    );
+    let mut output = Vec::new();
+    external_sort_with_config::<i32, _, _>(&encode(&data)[..], &mut output, &config).unwrap();
+
+    decode::<i32>(&output) == data_copy && is_empty_dir(&config)
+}
+
+#[quickcheck]
+fn stability(data: Vec<(u8, u32)>) -> bool {
+    let data: Vec<_> = data
+        .into_iter()
+        .map(|(key, tag)| Keyed { key: key % 8, tag })
+        .collect();
+    let mut data_copy = data.clone();
+    data_copy.sort_by_key(|k| k.key);
+
+    let config = config(
+        "stability",
+        3 * std::mem::size_of::<Keyed>(),
+        DEFAULT_MAX_FAN_IN,
+    );
+    let mut output = Vec::new();
+    external_sort_with_config::<Keyed, _, _>(&encode(&data)[..], &mut output, &config).unwrap();
+
+    decode::<Keyed>(&output) == data_copy && is_empty_dir(&config)
+}
+
+#[quickcheck]
+fn strings(data: Vec<String>) -> bool {
+    let mut data_copy = data.clone();
+    data_copy.sort_unstable();
+
+    let config = config("strings", 256, DEFAULT_MAX_FAN_IN);
+    let mut output = Vec::new();
+    external_sort_with_config::<String, _, _>(&encode(&data)[..], &mut output, &config).unwrap();
+
+    decode::<String>(&output) == data_copy && is_empty_dir(&config)
+}
+
+#[quickcheck]
+fn multiple_merge_passes(data: Vec<(u8, u32)>) -> bool {
+    let data: Vec<_> = data
+        .into_iter()
+        .map(|(key, tag)| Keyed { key: key % 8, tag })
+        .collect();
+    let mut data_copy = data.clone();
+    data_copy.sort_by_key(|k| k.key);
+
+    // One record per run, merged two at a time, takes about lg n passes.
+    let config = config("passes", 0, 2);
+    let mut output = Vec::new();
+    external_sort_with_config::<Keyed, _, _>(&encode(&data)[..], &mut output, &config).unwrap();
+
+    decode::<Keyed>(&output) == data_copy && is_empty_dir(&config)
+}
+
+#[test]
+fn more_runs_than_fan_in() {
+    let data: Vec<u32> = (0..100).rev().collect();
+
+    // 100 runs of one record with a fan-in of 3 need four passes before the final merge.
+    let config = config("fan-in", 0, 3);
+    let mut output = Vec::new();
+    external_sort_with_config::<u32, _, _>(&encode(&data)[..], &mut output, &config).unwrap();
+
+    assert_eq!(decode::<u32>(&output), (0..100).collect::<Vec<_>>());
+    assert!(is_empty_dir(&config));
+}
+
+#[test]
+fn lines() {
+    let input = "delta\nalpha\ncharlie\nbravo\nalpha";
+
+    let config = config("lines", 0, DEFAULT_MAX_FAN_IN);
+    let mut output = Vec::new();
+    external_sort_with_config::<Line, _, _>(input.as_bytes(), &mut output, &config).unwrap();
+
+    assert_eq!(output, b"alpha\nalpha\nbravo\ncharlie\ndelta\n");
+    assert!(is_empty_dir(&config));
+}
+
+#[test]
+fn in_memory() {
+    let mut output = Vec::new();
+    external_sort::<u16, _, _>(&encode(&[3u16, 1, 2])[..], &mut output).unwrap();
+    assert_eq!(decode::<u16>(&output), vec![1, 2, 3]);
+}
+
+#[test]
+fn truncated_input() {
+    let mut input = encode(&[1u32, 2, 3]);
+    input.pop();
+
+    let result = external_sort::<u32, _, _>(&input[..], io::sink());
+    assert_eq!(result.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn newline_in_line() {
+    let mut output = Vec::new();
+    let error = Line("two\nlines".to_string())
+        .write_to(&mut output)
+        .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut output = Vec::new();
+    external_sort::<i32, _, _>(io::empty(), &mut output).unwrap();
+    assert!(output.is_empty());
+}
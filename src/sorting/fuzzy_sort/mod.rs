@@ -0,0 +1,132 @@
+/*! # Problem 7-6 - Fuzzy Sorting of Intervals
+
+ **Input:** A sequence of `n` closed intervals `([a_1, b_1], [a_2, b_2], ..., [a_n, b_n])`
+
+ **Output:** A permutation `([a'_1, b'_1], ..., [a'_n, b'_n])` of the input sequence such that
+ there are `c_i` in `[a'_i, b'_i]` with `c_1 <= c_2 <= ... <= c_n`
+
+ **Time complexity:** `O(n lg n)` expected, `O(n)` expected if all intervals overlap
+
+ When we only know each value up to an interval, we cannot sort the values themselves, but we can
+ still put the intervals into an order that agrees with _some_ choice of values from them. The
+ algorithm is a variation of randomized quicksort: we pick a random interval as the pivot and
+ shrink it to the part that it has in common with the intervals overlapping it. Intervals that end
+ before this common region go to the left, intervals that start after it go to the right, and all
+ the rest contain the whole region. The latter are fuzzily equal to one another, as any point in
+ the region can serve as the `c_i` for all of them, so we only need to recurse into the left and
+ right parts. The more the intervals overlap, the more of them land in the middle, and the faster
+ the algorithm gets.
+*/
+use std::cmp::Ordering;
+
+use crate::util::{random::Random, slice};
+
+#[cfg(test)]
+mod test;
+
+/// The closed interval `[start, end]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T>
+where
+    T: PartialOrd,
+{
+    /// Construct the interval `[start, end]`.
+    ///
+    /// ## Panics
+    /// This function panics if `start` is larger than `end`.
+    pub fn new(start: T, end: T) -> Interval<T> {
+        assert!(
+            start <= end,
+            "Interval start must not be larger than its end!"
+        );
+        Interval { start, end }
+    }
+
+    /// Whether the two intervals have a point in common.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+pub fn fuzzy_sort<T>(data: &mut [Interval<T>])
+where
+    T: PartialOrd + Clone,
+{
+    fuzzy_sort_recursion(data, &mut Random::new());
+}
+
+fn fuzzy_sort_recursion<T>(data: &mut [Interval<T>], random: &mut Random)
+where
+    T: PartialOrd + Clone,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    let pivot = data[random.range(0..data.len())].clone();
+    let (less, greater) = fuzzy_partition(data, pivot);
+
+    let greater_start = data.len() - greater;
+    fuzzy_sort_recursion(&mut data[..less], random);
+    fuzzy_sort_recursion(&mut data[greater_start..], random);
+}
+
+// Partition `data` into the intervals lying entirely before the region that all intervals
+// overlapping `pivot` have in common, the ones containing that region, and the ones lying entirely
+// after it. Returns the sizes of the first and the last part.
+fn fuzzy_partition<T>(data: &mut [Interval<T>], mut region: Interval<T>) -> (usize, usize)
+where
+    T: PartialOrd + Clone,
+{
+    // Shrink the region to its intersection with every interval overlapping it. An interval
+    // overlapping the region at some point contains whatever the region shrinks to later, so after
+    // this loop, the final region is contained in every interval that overlaps it.
+    for interval in data.iter() {
+        if interval.overlaps(&region) {
+            if interval.start > region.start {
+                region.start = interval.start.clone();
+            }
+            if interval.end < region.end {
+                region.end = interval.end.clone();
+            }
+        }
+    }
+
+    let classify = |interval: &Interval<T>| {
+        if interval.end < region.start {
+            Ordering::Less
+        } else if interval.start > region.end {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    };
+
+    // From here on, this is the three-way partitioning of `quick_sort::three_way_partition`, except
+    // that the pivot isn't an element of `data`.
+    let mut lt = 0;
+    let mut i = 0;
+    let mut gt = data.len();
+
+    while i < gt {
+        match classify(&data[i]) {
+            Ordering::Less => {
+                slice::swap(data, lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                slice::swap(data, i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+
+    (lt, data.len() - gt)
+}
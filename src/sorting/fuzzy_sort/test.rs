@@ -0,0 +1,74 @@
+use quickcheck_macros::quickcheck;
+
+use super::{fuzzy_sort, Interval};
+
+fn intervals(data: Vec<(i16, u8)>) -> Vec<Interval<i32>> {
+    data.into_iter()
+        .map(|(start, length)| Interval::new(start as i32, start as i32 + length as i32))
+        .collect()
+}
+
+// Greedily pick the smallest point in each interval that isn't smaller than the previous one.
+fn is_fuzzily_sorted(data: &[Interval<i32>]) -> bool {
+    let mut c = i32::MIN;
+    for interval in data {
+        c = c.max(interval.start);
+        if c > interval.end {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[quickcheck]
+fn fuzzy_sortedness(data: Vec<(i16, u8)>) -> bool {
+    let mut data = intervals(data);
+    fuzzy_sort(&mut data);
+    is_fuzzily_sorted(&data)
+}
+
+#[quickcheck]
+fn fuzzy_sortedness_preservation(data: Vec<(i16, u8)>) -> bool {
+    let mut data = intervals(data);
+    let mut data_copy = data.clone();
+    fuzzy_sort(&mut data);
+
+    let key = |i: &Interval<i32>| (i.start, i.end);
+    data.sort_unstable_by_key(key);
+    data_copy.sort_unstable_by_key(key);
+    data == data_copy
+}
+
+#[quickcheck]
+fn points_are_sorted(data: Vec<i32>) -> bool {
+    let mut data: Vec<_> = data.into_iter().map(|d| Interval::new(d, d)).collect();
+    fuzzy_sort(&mut data);
+    data.iter().map(|i| i.start).is_sorted()
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<Interval<i32>> = Vec::new();
+    fuzzy_sort(&mut nothing);
+    assert!(nothing.is_empty());
+}
+
+#[test]
+fn all_overlapping() {
+    let mut data: Vec<_> = (0..10).map(|i| Interval::new(i, 10 + i)).rev().collect();
+    let data_copy = data.clone();
+
+    // All intervals contain the point 10, so any order is a fuzzy sorting.
+    fuzzy_sort(&mut data);
+    assert!(is_fuzzily_sorted(&data));
+    assert!(data.iter().all(|i| data_copy.contains(i)));
+}
+
+#[test]
+#[should_panic]
+fn reversed_interval() {
+    Interval::new(1, 0);
+}
@@ -0,0 +1,242 @@
+/*! # Problem 6-2 - d-ary Heaps
+
+ **Input:** A sequence of `n` elements of a partial order `(a_1, a_2, ..., a_n)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(d n log_d n)`
+
+ A d-ary heap is like a binary heap, except that every node has up to `d` children instead of two.
+ This makes the tree shallower, with a height of `Θ(log_d n)`: operations that only float elements
+ up (INSERT, INCREASE-KEY) get faster, whereas MAX-HEAPIFY has to look at `d` children per level and
+ gets slower, at `O(d log_d n)`. In practice, the wider nodes tend to make better use of cache lines,
+ so that a 4-ary heap often beats the binary one.
+
+ The arity is a const generic parameter, and the API mirrors that of `heap_sort::BinaryHeap`.
+ Again, we index from zero: the children of node `i` are `d i + 1, ..., d i + d`.
+*/
+use std::cmp::Ordering;
+
+use crate::util::{cmp::partial_compare, slice};
+
+#[cfg(test)]
+mod test;
+
+/// Index of the parent of node `i` in a `D`-ary heap. The root has no parent, so `i` must be
+/// positive.
+pub fn parent<const D: usize>(i: usize) -> usize {
+    (i - 1) / D
+}
+
+/// Index of the `k`-th child (counting from zero) of node `i` in a `D`-ary heap.
+pub fn child<const D: usize>(i: usize, k: usize) -> usize {
+    D * i + k + 1
+}
+
+/// Let the element at index `i` float down in `data`, assuming that all the `D`-ary trees rooted
+/// at the children of `i` are max-heaps. Afterwards, the tree rooted at `i` is a max-heap.
+pub fn max_heapify<T, const D: usize>(data: &mut [T], i: usize)
+where
+    T: PartialOrd,
+{
+    max_heapify_by::<T, _, D>(data, i, partial_compare);
+}
+
+/// Like `max_heapify`, but for a heap ordered by `compare`.
+pub fn max_heapify_by<T, F, const D: usize>(data: &mut [T], mut i: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    const { assert!(D >= 2, "A d-ary heap needs an arity of at least 2") };
+
+    loop {
+        let mut largest = i;
+        for k in 0..D {
+            let c = child::<D>(i, k);
+            if c >= data.len() {
+                break;
+            }
+
+            if compare(&data[c], &data[largest]) == Ordering::Greater {
+                largest = c;
+            }
+        }
+
+        if largest == i {
+            return;
+        }
+
+        slice::swap(data, i, largest);
+        i = largest;
+    }
+}
+
+/// Rearrange `data` into a `D`-ary max-heap in `O(n)` time.
+pub fn build_max_heap<T, const D: usize>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    build_max_heap_by::<T, _, D>(data, partial_compare);
+}
+
+/// Like `build_max_heap`, but for a heap ordered by `compare`.
+pub fn build_max_heap_by<T, F, const D: usize>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    // Every node after the parent of the last node is a leaf.
+    for i in (0..=parent::<D>(data.len() - 1)).rev() {
+        max_heapify_by::<T, _, D>(data, i, &mut compare);
+    }
+}
+
+pub fn d_ary_heap_sort<T, const D: usize>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    d_ary_heap_sort_by::<T, _, D>(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn d_ary_heap_sort_by_key<T, K, F, const D: usize>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    d_ary_heap_sort_by::<T, _, D>(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn d_ary_heap_sort_by<T, F, const D: usize>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    build_max_heap_by::<T, _, D>(data, &mut compare);
+
+    // The same as `DaryHeap::sort`: move the maximum behind the shrinking heap, one at a time.
+    for heap_size in (0..data.len()).rev() {
+        slice::swap(data, 0, heap_size);
+        max_heapify_by::<T, _, D>(&mut data[..heap_size], 0, &mut compare);
+    }
+}
+
+/// A `D`-ary max-heap living in a borrowed slice.
+///
+/// Only the first `heap_size()` elements of the slice belong to the heap; the rest of the slice is
+/// used as storage by `extract_max` (which moves the maximum behind the heap) and `insert` (which
+/// grows the heap into it).
+#[derive(Debug)]
+pub struct DaryHeap<'a, T, const D: usize> {
+    data: &'a mut [T],
+    heap_size: usize,
+}
+
+impl<'a, T, const D: usize> DaryHeap<'a, T, D>
+where
+    T: PartialOrd,
+{
+    /// Turn all of `data` into a heap by means of BUILD-MAX-HEAP.
+    pub fn new(data: &'a mut [T]) -> DaryHeap<'a, T, D> {
+        build_max_heap::<T, D>(data);
+        let heap_size = data.len();
+
+        DaryHeap { data, heap_size }
+    }
+
+    /// Create an empty heap that can grow into `data` by way of `insert`.
+    pub fn with_storage(data: &'a mut [T]) -> DaryHeap<'a, T, D> {
+        DaryHeap { data, heap_size: 0 }
+    }
+
+    /// The number of elements in the heap.
+    pub fn heap_size(&self) -> usize {
+        self.heap_size
+    }
+
+    /// Whether there are no elements in the heap.
+    pub fn is_empty(&self) -> bool {
+        self.heap_size == 0
+    }
+
+    /// The heap itself, in heap order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data[..self.heap_size]
+    }
+
+    /// MAX-HEAPIFY on the node at index `i` of the heap.
+    ///
+    /// ## Panics
+    /// This function panics if `i` is not smaller than the heap size.
+    pub fn max_heapify(&mut self, i: usize) {
+        assert!(i < self.heap_size, "Index {} out of heap bounds", i);
+        max_heapify::<T, D>(&mut self.data[..self.heap_size], i);
+    }
+
+    /// The largest element of the heap, if any.
+    pub fn maximum(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Remove the largest element from the heap. The element is moved to the slot right behind the
+    /// heap, and a reference to it is returned.
+    pub fn extract_max(&mut self) -> Option<&T> {
+        if self.heap_size == 0 {
+            return None;
+        }
+
+        self.heap_size -= 1;
+        slice::swap(self.data, 0, self.heap_size);
+        max_heapify::<T, D>(&mut self.data[..self.heap_size], 0);
+
+        Some(&self.data[self.heap_size])
+    }
+
+    /// Replace the element at index `i` of the heap by the larger `key`, and restore the heap
+    /// property by letting it float up.
+    ///
+    /// ## Panics
+    /// This function panics if `i` is not smaller than the heap size, or if `key` is smaller than
+    /// the element it replaces.
+    pub fn increase_key(&mut self, i: usize, key: T) {
+        assert!(i < self.heap_size, "Index {} out of heap bounds", i);
+        assert!(
+            key.partial_cmp(&self.data[i]) != Some(Ordering::Less),
+            "New key must not be smaller than the current key!"
+        );
+
+        self.data[i] = key;
+        self.float_up(i);
+    }
+
+    /// Add `key` to the heap, overwriting the slot of the underlying slice right behind the heap.
+    /// If the heap already spans all of the slice, `key` is handed back as an error.
+    pub fn insert(&mut self, key: T) -> Result<(), T> {
+        if self.heap_size == self.data.len() {
+            return Err(key);
+        }
+
+        self.data[self.heap_size] = key;
+        self.heap_size += 1;
+        self.float_up(self.heap_size - 1);
+
+        Ok(())
+    }
+
+    /// HEAPSORT: Repeatedly extract the maximum until the heap is empty, which leaves the heap's
+    /// elements in sorted order at the front of the underlying slice.
+    pub fn sort(mut self) {
+        while self.extract_max().is_some() {}
+    }
+
+    fn float_up(&mut self, mut i: usize) {
+        while i > 0 && self.data[parent::<D>(i)] < self.data[i] {
+            slice::swap(self.data, i, parent::<D>(i));
+            i = parent::<D>(i);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use quickcheck_macros::quickcheck;
+
+use super::{build_max_heap, child, d_ary_heap_sort, DaryHeap};
+
+fn is_max_heap<T: PartialOrd, const D: usize>(data: &[T]) -> bool {
+    (0..data.len()).all(|i| {
+        (0..D)
+            .map(|k| child::<D>(i, k))
+            .take_while(|&c| c < data.len())
+            .all(|c| data[c] <= data[i])
+    })
+}
+
+#[quickcheck]
+fn sortedness(mut data: Vec<i32>) -> bool {
+    let mut binary = data.clone();
+    let mut ternary = data.clone();
+
+    d_ary_heap_sort::<_, 2>(&mut binary);
+    d_ary_heap_sort::<_, 3>(&mut ternary);
+    d_ary_heap_sort::<_, 4>(&mut data);
+
+    binary.is_sorted() && ternary.is_sorted() && data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    d_ary_heap_sort::<_, 5>(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn heap_property(mut data: Vec<i32>) -> bool {
+    let mut ternary = data.clone();
+
+    build_max_heap::<_, 3>(&mut ternary);
+    build_max_heap::<_, 4>(&mut data);
+
+    is_max_heap::<_, 3>(&ternary) && is_max_heap::<_, 4>(&data)
+}
+
+#[quickcheck]
+fn extraction_order(mut data: Vec<i32>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut heap = DaryHeap::<_, 4>::new(&mut data);
+    let mut extracted = Vec::new();
+    while let Some(&max) = heap.extract_max() {
+        extracted.push(max);
+    }
+
+    extracted == expected
+}
+
+#[quickcheck]
+fn insertion(data: Vec<i32>) -> bool {
+    let mut storage = vec![0; data.len()];
+    let mut heap = DaryHeap::<_, 3>::with_storage(&mut storage);
+
+    for &d in &data {
+        if heap.insert(d).is_err() || !is_max_heap::<_, 3>(heap.as_slice()) {
+            return false;
+        }
+    }
+
+    heap.insert(0).is_err() && heap.maximum() == data.iter().max()
+}
+
+#[quickcheck]
+fn increasing_keys(mut data: Vec<i32>, index: usize) -> bool {
+    if data.is_empty() {
+        return true;
+    }
+
+    let mut heap = DaryHeap::<_, 4>::new(&mut data);
+    let index = index % heap.heap_size();
+    heap.increase_key(index, i32::MAX);
+
+    is_max_heap::<_, 4>(heap.as_slice()) && heap.maximum() == Some(&i32::MAX)
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    d_ary_heap_sort::<_, 4>(&mut nothing);
+    assert!(nothing.is_empty());
+}
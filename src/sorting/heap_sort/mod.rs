@@ -0,0 +1,248 @@
+/*! # Chapter 6 - Heapsort
+
+ **Input:** A sequence of `n` elements of a partial order `(a_1, a_2, ..., a_n)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(n lg n)`
+
+ Heapsort combines the best of insertion sort and merge sort: like merge sort it runs in
+ `O(n lg n)` time, and like insertion sort it sorts in place, never needing more than a constant
+ amount of memory outside of the input. Both properties come from the (binary max-)heap, a complete
+ binary tree stored level by level in the array itself in which every node is at least as large as
+ its children.
+
+ The heap operations are useful in their own right, so besides `heap_sort` this module exposes
+ MAX-HEAPIFY and BUILD-MAX-HEAP as free functions over slices, as well as a `BinaryHeap` type that
+ keeps track of the heap size like the book's `A.heap-size` attribute. Note that we index from
+ zero, so the children of node `i` are `2i + 1` and `2i + 2` rather than `2i` and `2i + 1`.
+*/
+use std::cmp::Ordering;
+
+use crate::util::{cmp::partial_compare, slice};
+
+pub mod d_ary;
+
+#[cfg(test)]
+mod test;
+
+/// Index of the parent of node `i`. The root has no parent, so `i` must be positive.
+pub fn parent(i: usize) -> usize {
+    (i - 1) / 2
+}
+
+/// Index of the left child of node `i`.
+pub fn left(i: usize) -> usize {
+    2 * i + 1
+}
+
+/// Index of the right child of node `i`.
+pub fn right(i: usize) -> usize {
+    2 * i + 2
+}
+
+/// Let the element at index `i` float down in `data`, assuming that the binary trees rooted at
+/// `left(i)` and `right(i)` are max-heaps. Afterwards, the tree rooted at `i` is a max-heap.
+pub fn max_heapify<T>(data: &mut [T], i: usize)
+where
+    T: PartialOrd,
+{
+    max_heapify_by(data, i, partial_compare);
+}
+
+/// Like `max_heapify`, but for a heap ordered by `compare`.
+pub fn max_heapify_by<T, F>(data: &mut [T], mut i: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // The book's version is recursive; since the recursive call is the very last thing that
+    // happens, we can just as well loop instead (cf. exercise 6.2-5).
+    loop {
+        let l = left(i);
+        let r = right(i);
+
+        let mut largest = i;
+        if l < data.len() && compare(&data[l], &data[largest]) == Ordering::Greater {
+            largest = l;
+        }
+        if r < data.len() && compare(&data[r], &data[largest]) == Ordering::Greater {
+            largest = r;
+        }
+
+        if largest == i {
+            return;
+        }
+
+        slice::swap(data, i, largest);
+        i = largest;
+    }
+}
+
+/// Rearrange `data` into a max-heap in `O(n)` time.
+pub fn build_max_heap<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    build_max_heap_by(data, partial_compare);
+}
+
+/// Like `build_max_heap`, but for a heap ordered by `compare`.
+pub fn build_max_heap_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // All nodes from `data.len() / 2` onwards are leaves, and hence trivially max-heaps already.
+    for i in (0..data.len() / 2).rev() {
+        max_heapify_by(data, i, &mut compare);
+    }
+}
+
+pub fn heap_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    heap_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn heap_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    heap_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn heap_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    build_max_heap_by(data, &mut compare);
+
+    // The same as `BinaryHeap::sort`: move the maximum behind the shrinking heap, one at a time.
+    for heap_size in (0..data.len()).rev() {
+        slice::swap(data, 0, heap_size);
+        max_heapify_by(&mut data[..heap_size], 0, &mut compare);
+    }
+}
+
+/// A binary max-heap living in a borrowed slice.
+///
+/// Only the first `heap_size()` elements of the slice belong to the heap; the rest of the slice is
+/// used as storage by `extract_max` (which moves the maximum behind the heap) and `insert` (which
+/// grows the heap into it).
+#[derive(Debug)]
+pub struct BinaryHeap<'a, T> {
+    data: &'a mut [T],
+    heap_size: usize,
+}
+
+impl<'a, T> BinaryHeap<'a, T>
+where
+    T: PartialOrd,
+{
+    /// Turn all of `data` into a heap by means of BUILD-MAX-HEAP.
+    pub fn new(data: &'a mut [T]) -> BinaryHeap<'a, T> {
+        build_max_heap(data);
+        let heap_size = data.len();
+
+        BinaryHeap { data, heap_size }
+    }
+
+    /// Create an empty heap that can grow into `data` by way of `insert`.
+    pub fn with_storage(data: &'a mut [T]) -> BinaryHeap<'a, T> {
+        BinaryHeap { data, heap_size: 0 }
+    }
+
+    /// The number of elements in the heap.
+    pub fn heap_size(&self) -> usize {
+        self.heap_size
+    }
+
+    /// Whether there are no elements in the heap.
+    pub fn is_empty(&self) -> bool {
+        self.heap_size == 0
+    }
+
+    /// The heap itself, in heap order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data[..self.heap_size]
+    }
+
+    /// MAX-HEAPIFY on the node at index `i` of the heap.
+    ///
+    /// ## Panics
+    /// This function panics if `i` is not smaller than the heap size.
+    pub fn max_heapify(&mut self, i: usize) {
+        assert!(i < self.heap_size, "Index {} out of heap bounds", i);
+        max_heapify(&mut self.data[..self.heap_size], i);
+    }
+
+    /// The largest element of the heap, if any.
+    pub fn maximum(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Remove the largest element from the heap. The element is moved to the slot right behind the
+    /// heap, and a reference to it is returned.
+    pub fn extract_max(&mut self) -> Option<&T> {
+        if self.heap_size == 0 {
+            return None;
+        }
+
+        self.heap_size -= 1;
+        slice::swap(self.data, 0, self.heap_size);
+        max_heapify(&mut self.data[..self.heap_size], 0);
+
+        Some(&self.data[self.heap_size])
+    }
+
+    /// Replace the element at index `i` of the heap by the larger `key`, and restore the heap
+    /// property by letting it float up.
+    ///
+    /// ## Panics
+    /// This function panics if `i` is not smaller than the heap size, or if `key` is smaller than
+    /// the element it replaces.
+    pub fn increase_key(&mut self, mut i: usize, key: T) {
+        assert!(i < self.heap_size, "Index {} out of heap bounds", i);
+        assert!(
+            key.partial_cmp(&self.data[i]) != Some(Ordering::Less),
+            "New key must not be smaller than the current key!"
+        );
+
+        self.data[i] = key;
+        while i > 0 && self.data[parent(i)] < self.data[i] {
+            slice::swap(self.data, i, parent(i));
+            i = parent(i);
+        }
+    }
+
+    /// Add `key` to the heap, overwriting the slot of the underlying slice right behind the heap.
+    /// If the heap already spans all of the slice, `key` is handed back as an error.
+    pub fn insert(&mut self, key: T) -> Result<(), T> {
+        if self.heap_size == self.data.len() {
+            return Err(key);
+        }
+
+        // Instead of inserting `-\infty` and increasing it to `key` as the book does, we put `key`
+        // in place right away and only do the floating up part of `increase_key`.
+        let mut i = self.heap_size;
+        self.data[i] = key;
+        self.heap_size += 1;
+
+        while i > 0 && self.data[parent(i)] < self.data[i] {
+            slice::swap(self.data, i, parent(i));
+            i = parent(i);
+        }
+
+        Ok(())
+    }
+
+    /// HEAPSORT: Repeatedly extract the maximum until the heap is empty, which leaves the heap's
+    /// elements in sorted order at the front of the underlying slice.
+    pub fn sort(mut self) {
+        while self.extract_max().is_some() {}
+    }
+}
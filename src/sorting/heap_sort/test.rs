@@ -0,0 +1,87 @@
+use quickcheck_macros::quickcheck;
+
+use super::{build_max_heap, heap_sort, left, right, BinaryHeap};
+
+fn is_max_heap<T: PartialOrd>(data: &[T]) -> bool {
+    (0..data.len()).all(|i| {
+        (left(i) >= data.len() || data[left(i)] <= data[i])
+            && (right(i) >= data.len() || data[right(i)] <= data[i])
+    })
+}
+
+#[quickcheck]
+fn sortedness(mut data: Vec<i32>) -> bool {
+    heap_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    heap_sort(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn heap_property(mut data: Vec<i32>) -> bool {
+    build_max_heap(&mut data);
+    is_max_heap(&data)
+}
+
+#[quickcheck]
+fn extraction_order(mut data: Vec<i32>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut heap = BinaryHeap::new(&mut data);
+    let mut extracted = Vec::new();
+    while let Some(&max) = heap.extract_max() {
+        extracted.push(max);
+    }
+
+    extracted == expected
+}
+
+#[quickcheck]
+fn insertion(data: Vec<i32>) -> bool {
+    let mut storage = vec![0; data.len()];
+    let mut heap = BinaryHeap::with_storage(&mut storage);
+
+    for &d in &data {
+        if heap.insert(d).is_err() || !is_max_heap(heap.as_slice()) {
+            return false;
+        }
+    }
+
+    heap.insert(0).is_err() && heap.maximum() == data.iter().max()
+}
+
+#[quickcheck]
+fn increasing_keys(mut data: Vec<i32>, index: usize) -> bool {
+    if data.is_empty() {
+        return true;
+    }
+
+    let mut heap = BinaryHeap::new(&mut data);
+    let index = index % heap.heap_size();
+    heap.increase_key(index, i32::MAX);
+
+    is_max_heap(heap.as_slice()) && heap.maximum() == Some(&i32::MAX)
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    heap_sort(&mut nothing);
+    assert!(nothing.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn decreasing_key() {
+    let mut data = vec![3, 2, 1];
+    BinaryHeap::new(&mut data).increase_key(0, 0);
+}
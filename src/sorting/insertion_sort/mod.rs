@@ -11,15 +11,33 @@
  collections. The implementation presented here differs significantly from the pseudocode in the
  book, mainly due to having to circumnavigate borrowing rules.
 */
+use std::cmp::Ordering;
+
+use crate::util::{cmp::partial_compare, slice};
 
 #[cfg(test)]
 mod test;
 
-// N. b. we limit ourselves to sorting vectors because there are currently no (?) useful
-// abstractions around indexing and swapping elements.
 pub fn insertion_sort<T>(data: &mut [T])
 where
     T: PartialOrd,
+{
+    insertion_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn insertion_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    insertion_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn insertion_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
 {
     // Starting with the second element because a one-element array is trivially sorted.
     for j in 1..data.len() {
@@ -30,8 +48,8 @@ where
         // than its left-next neighbor. In contrast, the book version seemingly "picks out" the
         // element, then shifts all the other elements right in its search for the right place, and
         // finally inserts the element.
-        while i > 0 && data[i - 1] > data[i] {
-            data.swap(i - 1, i);
+        while i > 0 && compare(&data[i - 1], &data[i]) == Ordering::Greater {
+            slice::swap(data, i - 1, i);
             i -= 1;
         }
     }
@@ -0,0 +1,110 @@
+/*! # Exercise 6.5-9 - Merging k Sorted Lists
+
+ **Input:** `k` sorted sequences with a total of `n` elements of a partial order
+
+ **Output:** A sorted sequence of all `n` elements
+
+ **Time complexity:** `O(n lg k)`
+
+ MERGE in merge sort combines two sorted sequences by repeatedly taking the smaller of their first
+ elements. With `k` sequences, finding the smallest of the first elements naively takes `Θ(k)`
+ comparisons. Keeping them in a min-priority queue instead brings that down to `O(lg k)`: we
+ extract the minimum, and insert the next element of the sequence it came from in its place.
+
+ The queue is keyed by pairs of elements and the index of their sequence, so that of several equal
+ elements, the one from the earliest sequence goes first. Since elements of the same sequence enter
+ the queue one after another, the merge is stable. The merge is lazy, only pulling elements from
+ the sequences as needed, and hence works for iterators of any size.
+*/
+use std::iter::FusedIterator;
+
+use crate::priority_queue::MinPriorityQueue;
+
+#[cfg(test)]
+mod test;
+
+/// A lazy merge of sorted iterators, see `k_way_merge`.
+pub struct KWayMerge<I>
+where
+    I: Iterator,
+{
+    sources: Vec<I>,
+    queue: MinPriorityQueue<(I::Item, usize), ()>,
+}
+
+/// Merge the sorted `sources` into a single sorted iterator.
+pub fn k_way_merge<S, I>(sources: S) -> KWayMerge<I::IntoIter>
+where
+    S: IntoIterator<Item = I>,
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    let mut sources: Vec<_> = sources.into_iter().map(IntoIterator::into_iter).collect();
+    let mut queue = MinPriorityQueue::new();
+
+    for (i, source) in sources.iter_mut().enumerate() {
+        if let Some(item) = source.next() {
+            queue.insert((item, i), ());
+        }
+    }
+
+    KWayMerge { sources, queue }
+}
+
+/// Merge the sorted `sources` into `output`.
+///
+/// ## Panics
+/// This function panics if `output` doesn't have room for exactly all elements of `sources`.
+pub fn k_way_merge_into<S, I>(sources: S, output: &mut [I::Item])
+where
+    S: IntoIterator<Item = I>,
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    let mut merge = k_way_merge(sources);
+
+    for slot in output.iter_mut() {
+        *slot = merge.next().expect("Output is larger than the input");
+    }
+
+    assert!(merge.next().is_none(), "Output is smaller than the input");
+}
+
+impl<I> Iterator for KWayMerge<I>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let ((item, i), ()) = self.queue.extract_min()?;
+
+        if let Some(next) = self.sources[i].next() {
+            self.queue.insert((next, i), ());
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queued = self.queue.len();
+        self.sources.iter().map(Iterator::size_hint).fold(
+            (queued, Some(queued)),
+            |(low, high), (l, h)| {
+                (
+                    low.saturating_add(l),
+                    high.zip(h).and_then(|(a, b)| a.checked_add(b)),
+                )
+            },
+        )
+    }
+}
+
+// Exhausted sources are never polled again, and once the queue runs empty, it stays empty.
+impl<I> FusedIterator for KWayMerge<I>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+}
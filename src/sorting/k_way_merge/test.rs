@@ -0,0 +1,87 @@
+use quickcheck_macros::quickcheck;
+
+use super::{k_way_merge, k_way_merge_into};
+
+#[quickcheck]
+fn sortedness_preservation(mut sources: Vec<Vec<i32>>) -> bool {
+    sources.iter_mut().for_each(|s| s.sort_unstable());
+
+    let mut expected: Vec<_> = sources.concat();
+    expected.sort_unstable();
+
+    let merged: Vec<_> = k_way_merge(sources).collect();
+    merged == expected
+}
+
+#[quickcheck]
+fn stability(mut sources: Vec<Vec<u8>>) -> bool {
+    sources
+        .iter_mut()
+        .for_each(|s| s.sort_unstable_by_key(|x| x % 8));
+
+    // Tag every element with its source and position, and only compare the keys.
+    #[derive(Debug, PartialEq)]
+    struct Tagged(u8, usize, usize);
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            (self.0 % 8).partial_cmp(&(other.0 % 8))
+        }
+    }
+
+    let tagged = sources
+        .iter()
+        .enumerate()
+        .map(|(i, s)| s.iter().enumerate().map(move |(j, &x)| Tagged(x, i, j)));
+    let merged: Vec<_> = k_way_merge(tagged).collect();
+
+    merged.len() == sources.iter().map(Vec::len).sum()
+        && merged.windows(2).all(|w| {
+            let (a, b) = (&w[0], &w[1]);
+            a.0 % 8 < b.0 % 8 || (a.0 % 8 == b.0 % 8 && (a.1, a.2) < (b.1, b.2))
+        })
+}
+
+#[test]
+fn slices() {
+    let a = [1, 4, 7];
+    let b = [2, 5, 8, 9];
+    let c = [0, 3, 6];
+
+    let merged: Vec<_> = k_way_merge([&a[..], &b[..], &c[..]]).copied().collect();
+    assert_eq!(merged, (0..10).collect::<Vec<_>>());
+
+    let mut output = [0; 10];
+    k_way_merge_into([a.to_vec(), b.to_vec(), c.to_vec()], &mut output);
+    assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn size_hint() {
+    let merge = k_way_merge([vec![1, 3], vec![2], vec![]]);
+    assert_eq!(merge.size_hint(), (3, Some(3)));
+}
+
+#[test]
+#[should_panic]
+fn output_too_small() {
+    let mut output = [0; 2];
+    k_way_merge_into([vec![1, 3], vec![2]], &mut output);
+}
+
+#[test]
+#[should_panic]
+fn output_too_large() {
+    let mut output = [0; 4];
+    k_way_merge_into([vec![1, 3], vec![2]], &mut output);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let nothing: Vec<Vec<i32>> = Vec::new();
+    assert_eq!(k_way_merge(nothing).next(), None);
+
+    let empty_sources = vec![Vec::<i32>::new(); 3];
+    assert_eq!(k_way_merge(empty_sources).next(), None);
+}
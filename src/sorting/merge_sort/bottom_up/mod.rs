@@ -0,0 +1,147 @@
+/*! # Bottom-Up Merge Sort
+
+ **Input:** A sequence of `n` elements of a partial order `(a_1, a_2, ..., a_n)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(n lg n)`
+
+ The recursive merge sort allocates two fresh buffers for every single merge, adding up to `O(n lg
+ n)` allocations, which easily dominates the running time for small element types. The bottom-up
+ variant does without recursion: it first merges pairs of single elements, then pairs of the
+ resulting runs of length two, and so on, doubling the run length with every pass until only one
+ run is left.
+
+ Every pass merges all runs from one array into another, so a single scratch buffer of `n` elements
+ suffices: the passes simply alternate between reading from the input and writing to the buffer,
+ and vice versa. The elements are moved by swapping, which leaves the consumed part of the source
+ array in some arbitrary state, but we never look at that again. Hence, apart from filling the
+ buffer once, no element is ever cloned. The `with_buffer` functions take the buffer from the
+ caller, so that repeated sorts can reuse its allocation.
+*/
+use std::cmp::Ordering;
+
+use crate::util::{cmp::partial_compare, slice};
+
+#[cfg(test)]
+mod test;
+
+pub fn bottom_up_merge_sort<T>(data: &mut [T])
+where
+    T: PartialOrd + Clone,
+{
+    bottom_up_merge_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn bottom_up_merge_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    bottom_up_merge_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn bottom_up_merge_sort_by<T, F>(data: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    bottom_up_merge_sort_with_buffer_by(data, &mut Vec::new(), compare);
+}
+
+/// Sort `data`, using `buffer` as scratch space. The buffer is left empty, but keeps its capacity,
+/// so that no allocation happens if it already has room for `data.len()` elements.
+pub fn bottom_up_merge_sort_with_buffer<T>(data: &mut [T], buffer: &mut Vec<T>)
+where
+    T: PartialOrd + Clone,
+{
+    bottom_up_merge_sort_with_buffer_by(data, buffer, partial_compare);
+}
+
+/// Sort `data` into the order defined by `compare`, using `buffer` as scratch space, as in
+/// `bottom_up_merge_sort_with_buffer`.
+pub fn bottom_up_merge_sort_with_buffer_by<T, F>(
+    data: &mut [T],
+    buffer: &mut Vec<T>,
+    mut compare: F,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+
+    buffer.clear();
+    buffer.extend_from_slice(data);
+
+    // Whether the current runs are in `data`, as opposed to `buffer`.
+    let mut in_data = true;
+    let mut width = 1;
+    while width < n {
+        if in_data {
+            merge_pass(data, buffer, width, &mut compare);
+        } else {
+            merge_pass(buffer, data, width, &mut compare);
+        }
+
+        in_data = !in_data;
+        width *= 2;
+    }
+
+    if !in_data {
+        slice::swap_with_slice(data, buffer);
+    }
+
+    buffer.clear();
+}
+
+// Merge all pairs of adjacent runs of length `width` in `source` into `target`. The last run may
+// be shorter, or lack a partner altogether, in which case it is just moved over.
+fn merge_pass<T, F>(source: &mut [T], target: &mut [T], width: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = source.len();
+    for start in (0..n).step_by(2 * width) {
+        let middle = n.min(start + width);
+        let end = n.min(start + 2 * width);
+
+        merge(source, target, start, middle, end, compare);
+    }
+}
+
+// Merge the sorted runs `source[start..middle]` and `source[middle..end]` into
+// `target[start..end]`. On ties, the element of the left run goes first, keeping the sort stable.
+fn merge<T, F>(
+    source: &mut [T],
+    target: &mut [T],
+    start: usize,
+    middle: usize,
+    end: usize,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (mut i, mut j) = (start, middle);
+
+    for slot in &mut target[start..end] {
+        let take_right =
+            j < end && (i == middle || compare(&source[j], &source[i]) == Ordering::Less);
+
+        let next = if take_right {
+            j += 1;
+            j - 1
+        } else {
+            i += 1;
+            i - 1
+        };
+
+        slice::swap_values(slot, &mut source[next]);
+    }
+}
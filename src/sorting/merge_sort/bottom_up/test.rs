@@ -0,0 +1,58 @@
+use quickcheck_macros::quickcheck;
+
+use super::{
+    bottom_up_merge_sort, bottom_up_merge_sort_by, bottom_up_merge_sort_by_key,
+    bottom_up_merge_sort_with_buffer,
+};
+
+#[quickcheck]
+fn sortedness(mut data: Vec<i32>) -> bool {
+    bottom_up_merge_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    bottom_up_merge_sort(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    bottom_up_merge_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn stability_by_key(mut data: Vec<(u8, u8)>) -> bool {
+    let mut data_copy = data.clone();
+    bottom_up_merge_sort_by_key(&mut data, |&(key, _)| key % 8);
+    data_copy.sort_by_key(|&(key, _)| key % 8);
+    data == data_copy
+}
+
+#[test]
+fn buffer_reuse() {
+    let mut buffer = Vec::with_capacity(100);
+    let allocation = buffer.as_ptr();
+
+    for n in [100, 37, 64, 1, 99] {
+        let mut data: Vec<_> = (0..n).rev().collect();
+        bottom_up_merge_sort_with_buffer(&mut data, &mut buffer);
+
+        assert!(data.is_sorted());
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.as_ptr(), allocation);
+    }
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    bottom_up_merge_sort(&mut nothing);
+    assert!(nothing == Vec::new());
+}
@@ -0,0 +1,59 @@
+/*! # Indirect Merge Sort
+
+ **Input:** A sequence of `n` elements of a partial order `(a_1, a_2, ..., a_n)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(n lg n)`
+
+ MERGE needs somewhere to put the elements of the two halves while it writes the merged result
+ back, which is why `merge_sort` requires `T: Clone`. That rules out owning types that can't or
+ shouldn't be cloned, like file handles or large records. We can get around this by sorting the
+ indices of the elements instead, which can be copied freely: merge sort the indices `0..n` by the
+ elements they point to, and then move every element to its place in `O(n)` swaps, using
+ `slice::permute`.
+
+ Since merge sort is stable, so is this. The price is `O(n)` additional memory for the indices, and
+ the indirection when comparing, which is less kind to the cache than comparing adjacent elements.
+*/
+use std::cmp::Ordering;
+
+use super::merge_sort_by;
+use crate::util::{cmp::partial_compare, slice};
+
+#[cfg(test)]
+mod test;
+
+pub fn indirect_merge_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    indirect_merge_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn indirect_merge_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    indirect_merge_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn indirect_merge_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    merge_sort_by(&mut order, |&i, &j| compare(&data[i], &data[j]));
+
+    // `order` tells where each element comes from, `permute` wants to know where it goes to.
+    let mut destinations = vec![0; data.len()];
+    for (destination, &source) in order.iter().enumerate() {
+        destinations[source] = destination;
+    }
+
+    slice::permute(data, &mut destinations);
+}
@@ -0,0 +1,59 @@
+use quickcheck_macros::quickcheck;
+
+use super::{indirect_merge_sort, indirect_merge_sort_by, indirect_merge_sort_by_key};
+
+// A type that can be neither cloned nor copied.
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Unique(i32);
+
+#[quickcheck]
+fn sortedness(mut data: Vec<i32>) -> bool {
+    indirect_merge_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    indirect_merge_sort(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    indirect_merge_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn stability_by_key(mut data: Vec<(u8, u8)>) -> bool {
+    let mut data_copy = data.clone();
+    indirect_merge_sort_by_key(&mut data, |&(key, _)| key % 8);
+    data_copy.sort_by_key(|&(key, _)| key % 8);
+    data == data_copy
+}
+
+#[quickcheck]
+fn without_clone(data: Vec<i32>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    let mut unique: Vec<_> = data.into_iter().map(Unique).collect();
+    indirect_merge_sort(&mut unique);
+
+    let mut boxed: Vec<_> = expected.iter().rev().map(|&d| Box::new(d)).collect();
+    indirect_merge_sort(&mut boxed);
+
+    unique.iter().map(|u| u.0).eq(expected.iter().copied())
+        && boxed.iter().map(|b| **b).eq(expected.iter().copied())
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<Unique> = Vec::new();
+    indirect_merge_sort(&mut nothing);
+    assert!(nothing.is_empty());
+}
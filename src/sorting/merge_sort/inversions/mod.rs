@@ -0,0 +1,183 @@
+/*! # Problem 2-4 - Inversions
+
+ **Input:** A sequence of `n` elements of a partial order `A = (a_1, a_2, ..., a_n)`
+
+ **Output:** The number of inversions of `A`, i.e. pairs `(i, j)` with `i < j` and `a_i > a_j`
+
+ **Time complexity:** `O(n lg n)`
+
+ The number of inversions measures how far a sequence is from being sorted: there are none in a
+ sorted sequence, and `n (n - 1) / 2` in a reversed one. Checking all pairs takes `Θ(n^2)` time,
+ but merge sort can do better. When MERGE takes an element from the right subarray, that element
+ is smaller than all of the elements remaining in the left one, each of which it forms an
+ inversion with. Every inversion is found exactly once this way, at the merge that brings its two
+ elements together.
+
+ We don't want to reorder the input just to count, so we sort the indices of the elements instead.
+ Besides the mere count, the same procedure can list the inversions themselves, in `O(n lg n + k)`
+ time for `k` inversions, or tell for each element how many inversions it is involved in. The
+ latter works because an element of the left subarray inverts with exactly those elements of the
+ right subarray taken before it.
+
+ Counting the inversions of the ranks one ordering assigns to the elements of another gives their
+ Kendall tau distance, the number of pairs the two orderings disagree on.
+*/
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+/// Number of inversions of `data`.
+pub fn count_inversions<T>(data: &[T]) -> usize
+where
+    T: PartialOrd,
+{
+    let mut count = Count(0);
+    find_inversions(data, &mut count);
+    count.0
+}
+
+/// All inversions `(i, j)` of `data`, in no particular order.
+pub fn inversion_pairs<T>(data: &[T]) -> Vec<(usize, usize)>
+where
+    T: PartialOrd,
+{
+    let mut pairs = Pairs(Vec::new());
+    find_inversions(data, &mut pairs);
+    pairs.0
+}
+
+/// The number of inversions each element of `data` is involved in, on either side.
+pub fn inversions_per_element<T>(data: &[T]) -> Vec<usize>
+where
+    T: PartialOrd,
+{
+    let mut counts = PerElement(vec![0; data.len()]);
+    find_inversions(data, &mut counts);
+    counts.0
+}
+
+/// Number of pairs of elements that `a` and `b` order differently.
+///
+/// ## Panics
+/// This function panics if `a` and `b` aren't permutations of each other, or contain duplicates.
+pub fn kendall_tau_distance<T>(a: &[T], b: &[T]) -> usize
+where
+    T: Eq + Hash,
+{
+    assert_eq!(a.len(), b.len(), "Orderings of different lengths");
+
+    let positions: HashMap<&T, usize> = b.iter().enumerate().map(|(i, x)| (x, i)).collect();
+    assert_eq!(positions.len(), b.len(), "Ordering contains duplicates");
+
+    let mut seen = vec![false; b.len()];
+    let ranks: Vec<usize> = a
+        .iter()
+        .map(|x| {
+            let position = *positions.get(x).expect("Orderings of different elements");
+            assert!(!seen[position], "Ordering contains duplicates");
+            seen[position] = true;
+            position
+        })
+        .collect();
+
+    count_inversions(&ranks)
+}
+
+// What to do with the inversions found while merging.
+trait Tally {
+    // The element at index `right` comes before all of those at the indices `left` in sorted
+    // order, and inverts with each of them.
+    fn right_first(&mut self, right: usize, left: &[usize]);
+
+    // The element at index `left` comes after `count` elements of the right subarray.
+    fn left_after(&mut self, _left: usize, _count: usize) {}
+}
+
+struct Count(usize);
+
+impl Tally for Count {
+    fn right_first(&mut self, _right: usize, left: &[usize]) {
+        self.0 += left.len();
+    }
+}
+
+struct Pairs(Vec<(usize, usize)>);
+
+impl Tally for Pairs {
+    fn right_first(&mut self, right: usize, left: &[usize]) {
+        self.0.extend(left.iter().map(|&l| (l, right)));
+    }
+}
+
+struct PerElement(Vec<usize>);
+
+impl Tally for PerElement {
+    fn right_first(&mut self, right: usize, left: &[usize]) {
+        self.0[right] += left.len();
+    }
+
+    fn left_after(&mut self, left: usize, count: usize) {
+        self.0[left] += count;
+    }
+}
+
+fn find_inversions<T, U>(data: &[T], tally: &mut U)
+where
+    T: PartialOrd,
+    U: Tally,
+{
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    let mut buffer = Vec::with_capacity(data.len());
+    sort_indices(data, &mut order, &mut buffer, tally);
+}
+
+// Merge sort the indices `order` by the elements of `data` they point to.
+fn sort_indices<T, U>(data: &[T], order: &mut [usize], buffer: &mut Vec<usize>, tally: &mut U)
+where
+    T: PartialOrd,
+    U: Tally,
+{
+    if order.len() <= 1 {
+        return;
+    }
+
+    let middle = order.len() / 2;
+    sort_indices(data, &mut order[..middle], buffer, tally);
+    sort_indices(data, &mut order[middle..], buffer, tally);
+
+    merge(data, order, middle, buffer, tally);
+}
+
+// Merge the sorted halves `order[..middle]` and `order[middle..]`. Only elements that are strictly
+// smaller are taken from the right half first, since equal elements don't form inversions.
+fn merge<T, U>(
+    data: &[T],
+    order: &mut [usize],
+    middle: usize,
+    buffer: &mut Vec<usize>,
+    tally: &mut U,
+) where
+    T: PartialOrd,
+    U: Tally,
+{
+    let (left, right) = order.split_at(middle);
+    let (mut i, mut j) = (0, 0);
+
+    buffer.clear();
+    while i < left.len() {
+        if j < right.len() && data[right[j]] < data[left[i]] {
+            tally.right_first(right[j], &left[i..]);
+            buffer.push(right[j]);
+            j += 1;
+        } else {
+            tally.left_after(left[i], j);
+            buffer.push(left[i]);
+            i += 1;
+        }
+    }
+    buffer.extend_from_slice(&right[j..]);
+
+    order.copy_from_slice(buffer);
+}
@@ -0,0 +1,75 @@
+use quickcheck_macros::quickcheck;
+
+use super::{count_inversions, inversion_pairs, inversions_per_element, kendall_tau_distance};
+
+fn naive_inversion_pairs<T: PartialOrd>(data: &[T]) -> Vec<(usize, usize)> {
+    let n = data.len();
+    (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| data[i] > data[j])
+        .collect()
+}
+
+#[quickcheck]
+fn counts(data: Vec<u8>) -> bool {
+    count_inversions(&data) == naive_inversion_pairs(&data).len()
+}
+
+#[quickcheck]
+fn pairs(data: Vec<u8>) -> bool {
+    let mut pairs = inversion_pairs(&data);
+    pairs.sort_unstable();
+    pairs == naive_inversion_pairs(&data)
+}
+
+#[quickcheck]
+fn per_element(data: Vec<u8>) -> bool {
+    let mut expected = vec![0; data.len()];
+    for (i, j) in naive_inversion_pairs(&data) {
+        expected[i] += 1;
+        expected[j] += 1;
+    }
+
+    inversions_per_element(&data) == expected
+}
+
+#[test]
+fn reversed() {
+    let n = 1000;
+    let data: Vec<_> = (0..n).rev().collect();
+    assert_eq!(count_inversions(&data), n * (n - 1) / 2);
+}
+
+#[test]
+fn kendall_tau() {
+    let a = ["a", "b", "c", "d", "e"];
+    let b = ["c", "d", "a", "b", "e"];
+    let reversed = ["e", "d", "c", "b", "a"];
+
+    assert_eq!(kendall_tau_distance(&a, &a), 0);
+    assert_eq!(kendall_tau_distance(&a, &b), 4);
+    assert_eq!(kendall_tau_distance(&b, &a), 4);
+    assert_eq!(kendall_tau_distance(&a, &reversed), 10);
+}
+
+#[test]
+#[should_panic]
+fn kendall_tau_different_elements() {
+    kendall_tau_distance(&[1, 2, 3], &[1, 2, 4]);
+}
+
+#[test]
+#[should_panic]
+fn kendall_tau_duplicates() {
+    kendall_tau_distance(&[1, 1, 2], &[1, 2, 3]);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let nothing: Vec<i32> = Vec::new();
+    assert_eq!(count_inversions(&nothing), 0);
+    assert!(inversion_pairs(&nothing).is_empty());
+    assert!(inversions_per_element(&nothing).is_empty());
+}
@@ -16,52 +16,168 @@
  The optimal time complexity comes at a cost: for one, the merge operation requires an additional
  `O(n)` memory at each step. Further, the algorithm is highly unkind to modern CPU caching and
  memory fetching, incurring non-negligible practical costs.
+
+ ## Problem 2-1 - Insertion Sort on Small Arrays in Merge Sort
+
+ Although merge sort beats insertion sort asymptotically, the constant factors of insertion sort
+ are so much smaller that it wins on small inputs. So, we stop the recursion at subarrays of at
+ most `k` elements and sort them with insertion sort instead. Sorting the `n / k` subarrays of
+ length `k` takes `Θ(n k)` time, merging them `Θ(n lg(n / k))`; hence the whole thing still runs
+ in `Θ(n lg n)` time as long as `k = O(lg n)`. The best `k` in practice depends on the machine and
+ the data, so `MergeSortConfig::tuned` allows finding it empirically.
 */
 
-use crate::util::slice;
+use crate::sorting::insertion_sort::insertion_sort_by;
+use crate::util::{cmp::partial_compare, slice};
 
+use std::cmp::Ordering;
 use std::ops::{Range, RangeBounds};
+use std::time::{Duration, Instant};
+
+pub mod bottom_up;
+pub mod indirect;
+pub mod inversions;
+pub mod parallel;
 
 #[cfg(test)]
 mod test;
 
-pub fn merge_sort<T>(mut data: &mut [T])
+/// The insertion threshold used by `merge_sort` and friends.
+pub const DEFAULT_INSERTION_THRESHOLD: usize = 16;
+
+// Candidates and number of timed runs per candidate considered by `MergeSortConfig::tuned`.
+const TUNING_THRESHOLDS: Range<usize> = 1..65;
+const TUNING_ROUNDS: usize = 5;
+
+/// Configuration of the hybrid merge sort from problem 2-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeSortConfig {
+    /// Subarrays of at most this many elements are sorted by insertion sort instead of being split
+    /// further. Thresholds of zero or one give the plain merge sort.
+    pub insertion_threshold: usize,
+}
+
+impl Default for MergeSortConfig {
+    fn default() -> Self {
+        MergeSortConfig {
+            insertion_threshold: DEFAULT_INSERTION_THRESHOLD,
+        }
+    }
+}
+
+impl MergeSortConfig {
+    /// Find the insertion threshold that sorts `sample` fastest on the current machine, trying all
+    /// thresholds up to 64. The sample should be representative of the data to be sorted later.
+    pub fn tuned<T>(sample: &[T]) -> MergeSortConfig
+    where
+        T: PartialOrd + Clone,
+    {
+        let time = |insertion_threshold| {
+            let config = MergeSortConfig {
+                insertion_threshold,
+            };
+
+            // Taking the fastest of several runs filters out most of the noise.
+            (0..TUNING_ROUNDS)
+                .map(|_| {
+                    let mut data = sample.to_vec();
+                    let start = Instant::now();
+                    merge_sort_with_config(&mut data, config);
+                    start.elapsed()
+                })
+                .min()
+                .unwrap_or(Duration::ZERO)
+        };
+
+        let insertion_threshold = TUNING_THRESHOLDS.min_by_key(|&k| time(k)).unwrap();
+        MergeSortConfig {
+            insertion_threshold,
+        }
+    }
+}
+
+pub fn merge_sort<T>(data: &mut [T])
 where
     T: PartialOrd + Clone,
+{
+    merge_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn merge_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn merge_sort_by<T, F>(data: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort_with_config_by(data, MergeSortConfig::default(), compare);
+}
+
+/// Sort `data`, switching to insertion sort as configured by `config`.
+pub fn merge_sort_with_config<T>(data: &mut [T], config: MergeSortConfig)
+where
+    T: PartialOrd + Clone,
+{
+    merge_sort_with_config_by(data, config, partial_compare);
+}
+
+/// Sort `data` into the order defined by `compare`, switching to insertion sort as configured by
+/// `config`.
+pub fn merge_sort_with_config_by<T, F>(data: &mut [T], config: MergeSortConfig, mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
 {
     if data.len() <= 1 {
         return;
     }
 
     let end = data.len();
-    merge_sort_recursion(&mut data, 0..end);
+    merge_sort_recursion(data, 0..end, config.insertion_threshold, &mut compare);
 }
 
 // This is the actual main sorting function. For usability reasons, the actual `merge_sort`
 // function is a simple facade that also does some setup.
-fn merge_sort_recursion<T>(mut data: &mut [T], range: Range<usize>)
-where
-    T: PartialOrd + Clone,
+fn merge_sort_recursion<T, F>(
+    data: &mut [T],
+    range: Range<usize>,
+    insertion_threshold: usize,
+    compare: &mut F,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
 {
     let Range { start, end } = range;
 
-    if start < end - 1 {
+    if end - start <= insertion_threshold {
+        insertion_sort_by(&mut data[start..end], &mut *compare);
+    } else if start < end - 1 {
         let middle = (start + end) / 2;
-        merge_sort_recursion(&mut data, start..middle);
-        merge_sort_recursion(&mut data, middle..end);
+        merge_sort_recursion(data, start..middle, insertion_threshold, compare);
+        merge_sort_recursion(data, middle..end, insertion_threshold, compare);
 
-        merge(&mut data, start..middle, middle..end);
+        merge(data, start..middle, middle..end, compare);
     }
 }
 
 // This function is where the actual work happens: It merges two adjacent sorted subarrays of
 // `data`, namely the slices `data[left]` and `data[right]` into a whole, residing in the same
 // memory space. Unfortunately, this operation requires `O(n)` additional memory.
-fn merge<T, L, R>(data: &mut [T], left: L, right: R)
+fn merge<T, L, R, F>(data: &mut [T], left: L, right: R, compare: &mut F)
 where
-    T: PartialOrd + Clone,
+    T: Clone,
     L: RangeBounds<usize>,
     R: RangeBounds<usize>,
+    F: FnMut(&T, &T) -> Ordering,
 {
     let left = slice::range(left, ..data.len());
     let right = slice::range(right, ..data.len());
@@ -96,7 +212,7 @@ where
 
         // (2) take as many elements of `right` that are smaller than `item_l` as possible and
         // insert them into `data`; and...
-        while matches!(drain_right.peek(), Some(i) if i < &item_l) {
+        while matches!(drain_right.peek(), Some(i) if compare(i, &item_l) == Ordering::Less) {
             let item_r = drain_right.next().unwrap();
             assign_next(item_r);
         }
@@ -0,0 +1,262 @@
+/*! # Chapter 27.3 - Multithreaded Merge Sort
+
+ **Input:** A sequence of `n` elements of a partial order `(a_1, a_2, ..., a_n)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `Θ(n lg n)` work, `Θ(lg^3 n)` span
+
+ The two recursive calls of merge sort are independent of each other, so they can run in parallel.
+ That alone doesn't get us far, though: the final MERGE still takes `Θ(n)` time on a single thread,
+ bounding the speedup by `lg n`. P-MERGE parallelizes the merge as well. It takes the median `x` of
+ the larger of the two sorted runs, and finds its position in the other one using binary search.
+ Then `x` goes right between the elements smaller and larger than it, and merging those two groups
+ are again independent subproblems.
+
+ To stay stable, elements equal to the median have to end up on the correct side of it: those from
+ the left run before it, those from the right run after it. Hence we use `upper_bound` to search
+ the left run, and `lower_bound` to search the right one.
+
+ Spawning a thread per recursive call would drown the actual work in overhead, so below a grain size
+ we fall back to the sequential `merge_sort` and MERGE. We also stop forking once as many threads
+ as the configuration allows are busy. As with `bottom_up`, the passes alternate between the input
+ and a single scratch buffer, moving elements around by swapping rather than cloning.
+*/
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+use std::thread;
+
+use super::merge_sort_by;
+use crate::{
+    searching::binary_search::{lower_bound_by, upper_bound_by},
+    util::{cmp::partial_compare, slice},
+};
+
+#[cfg(test)]
+mod test;
+
+/// The grain size used by `parallel_merge_sort` and friends.
+pub const DEFAULT_GRAIN_SIZE: usize = 1 << 12;
+
+/// Configuration of the multithreaded merge sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParallelMergeSortConfig {
+    /// Sorts and merges of at most this many elements run sequentially.
+    pub grain_size: usize,
+    /// The maximum number of threads to sort with at a time.
+    pub threads: usize,
+}
+
+impl Default for ParallelMergeSortConfig {
+    /// Use as many threads as the machine offers.
+    fn default() -> Self {
+        ParallelMergeSortConfig {
+            grain_size: DEFAULT_GRAIN_SIZE,
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+}
+
+pub fn parallel_merge_sort<T>(data: &mut [T])
+where
+    T: PartialOrd + Clone + Send,
+{
+    parallel_merge_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn parallel_merge_sort_by_key<T, K, F>(data: &mut [T], key: F)
+where
+    T: Clone + Send,
+    K: PartialOrd,
+    F: Fn(&T) -> K + Sync,
+{
+    parallel_merge_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn parallel_merge_sort_by<T, F>(data: &mut [T], compare: F)
+where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    parallel_merge_sort_with_config_by(data, ParallelMergeSortConfig::default(), compare);
+}
+
+/// Sort `data` with the grain size and number of threads given by `config`.
+pub fn parallel_merge_sort_with_config<T>(data: &mut [T], config: ParallelMergeSortConfig)
+where
+    T: PartialOrd + Clone + Send,
+{
+    parallel_merge_sort_with_config_by(data, config, partial_compare);
+}
+
+/// Sort `data` into the order defined by `compare`, with the grain size and number of threads
+/// given by `config`.
+pub fn parallel_merge_sort_with_config_by<T, F>(
+    data: &mut [T],
+    config: ParallelMergeSortConfig,
+    compare: F,
+) where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    let mut buffer = data.to_vec();
+    sort(data, &mut buffer, false, config.threads, &config, &compare);
+}
+
+// Sort `data`, leaving the result either in `data` itself or, if `into_buffer` is set, in
+// `buffer`, which has to be of the same length. Either way, the other slice ends up in some
+// arbitrary order.
+fn sort<T, F>(
+    data: &mut [T],
+    buffer: &mut [T],
+    into_buffer: bool,
+    threads: usize,
+    config: &ParallelMergeSortConfig,
+    compare: &F,
+) where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if data.len() <= config.grain_size.max(1) || threads <= 1 {
+        merge_sort_by(data, compare);
+        if into_buffer {
+            slice::swap_with_slice(data, buffer);
+        }
+        return;
+    }
+
+    // Sort both halves into the slice we're *not* supposed to end up in, so that we can merge them
+    // into the right one.
+    let middle = data.len() / 2;
+    {
+        let (data_left, data_right) = data.split_at_mut(middle);
+        let (buffer_left, buffer_right) = buffer.split_at_mut(middle);
+        let left_threads = threads / 2;
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                sort(
+                    data_left,
+                    buffer_left,
+                    !into_buffer,
+                    left_threads,
+                    config,
+                    compare,
+                );
+            });
+            sort(
+                data_right,
+                buffer_right,
+                !into_buffer,
+                threads - left_threads,
+                config,
+                compare,
+            );
+        });
+    }
+
+    let (source, target) = if into_buffer {
+        (data, buffer)
+    } else {
+        (buffer, data)
+    };
+    let (left, right) = source.split_at_mut(middle);
+    p_merge(left, right, target, threads, config, compare);
+}
+
+// Merge the sorted runs `left` and `right` into `target`, splitting up the work between threads.
+fn p_merge<T, F>(
+    left: &mut [T],
+    right: &mut [T],
+    target: &mut [T],
+    threads: usize,
+    config: &ParallelMergeSortConfig,
+    compare: &F,
+) where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if target.len() <= config.grain_size.max(1) || threads <= 1 {
+        merge(left, right, target, compare);
+        return;
+    }
+
+    // Find the median of the larger run, and split the other one around it.
+    let median_in_left = left.len() >= right.len();
+    let (q_left, q_right) = if median_in_left {
+        let q_left = left.len() / 2;
+        (q_left, lower_bound_by(right, |x| compare(x, &left[q_left])))
+    } else {
+        let q_right = right.len() / 2;
+        (
+            upper_bound_by(left, |x| compare(x, &right[q_right])),
+            q_right,
+        )
+    };
+
+    let (left_low, left_high) = left.split_at_mut(q_left);
+    let (right_low, right_high) = right.split_at_mut(q_right);
+    let (target_low, target_high) = target.split_at_mut(q_left + q_right);
+    let (target_median, target_high) = target_high.split_first_mut().unwrap();
+
+    let (left_high, right_high) = if median_in_left {
+        let (median, left_high) = left_high.split_first_mut().unwrap();
+        slice::swap_values(target_median, median);
+        (left_high, right_high)
+    } else {
+        let (median, right_high) = right_high.split_first_mut().unwrap();
+        slice::swap_values(target_median, median);
+        (left_high, right_high)
+    };
+
+    let low_threads = threads / 2;
+    thread::scope(|s| {
+        s.spawn(|| {
+            p_merge(
+                left_low,
+                right_low,
+                target_low,
+                low_threads,
+                config,
+                compare,
+            )
+        });
+        p_merge(
+            left_high,
+            right_high,
+            target_high,
+            threads - low_threads,
+            config,
+            compare,
+        );
+    });
+}
+
+// The sequential MERGE, moving the elements of `left` and `right` into `target` by swapping. On
+// ties, the element of `left` goes first.
+fn merge<T, F>(left: &mut [T], right: &mut [T], target: &mut [T], compare: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let (mut i, mut j) = (0, 0);
+
+    for slot in target {
+        let take_right =
+            j < right.len() && (i == left.len() || compare(&right[j], &left[i]) == Ordering::Less);
+
+        if take_right {
+            slice::swap_values(slot, &mut right[j]);
+            j += 1;
+        } else {
+            slice::swap_values(slot, &mut left[i]);
+            i += 1;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use quickcheck_macros::quickcheck;
+
+use super::{
+    parallel_merge_sort, parallel_merge_sort_by, parallel_merge_sort_by_key,
+    parallel_merge_sort_with_config, parallel_merge_sort_with_config_by, ParallelMergeSortConfig,
+};
+use crate::util::random::Random;
+
+// A tiny grain size, so that even the small inputs quickcheck comes up with exercise the parallel
+// code paths.
+const FINE_GRAINED: ParallelMergeSortConfig = ParallelMergeSortConfig {
+    grain_size: 2,
+    threads: 8,
+};
+
+#[quickcheck]
+fn sortedness(mut data: Vec<i32>) -> bool {
+    parallel_merge_sort_with_config(&mut data, FINE_GRAINED);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    parallel_merge_sort_with_config(&mut data, FINE_GRAINED);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    parallel_merge_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn stability_by_key(data: Vec<(u8, u8)>) -> bool {
+    let mut data_copy = data.clone();
+    data_copy.sort_by_key(|&(key, _)| key % 8);
+
+    let mut fine_grained = data.clone();
+    parallel_merge_sort_with_config_by(&mut fine_grained, FINE_GRAINED, |a, b| {
+        (a.0 % 8).cmp(&(b.0 % 8))
+    });
+
+    let mut data = data;
+    parallel_merge_sort_by_key(&mut data, |&(key, _)| key % 8);
+
+    data == data_copy && fine_grained == data_copy
+}
+
+#[test]
+fn large_input() {
+    let mut random = Random::with_seed(27);
+    let mut data: Vec<_> = (0..100_000).map(|_| random.next_u64()).collect();
+    let mut data_copy = data.clone();
+
+    parallel_merge_sort(&mut data);
+    data_copy.sort_unstable();
+    assert_eq!(data, data_copy);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    parallel_merge_sort(&mut nothing);
+    assert!(nothing == Vec::new());
+}
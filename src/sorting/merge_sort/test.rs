@@ -1,6 +1,8 @@
 use quickcheck_macros::quickcheck;
 
-use super::merge_sort;
+use super::{
+    merge_sort, merge_sort_by, merge_sort_by_key, merge_sort_with_config, MergeSortConfig,
+};
 
 
 // If only `is_sorted` was already stabilized...
@@ -34,6 +36,40 @@ fn sortedness_preservation(mut data: Vec<i32>) -> bool {
 }
 
 
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    merge_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn stability_by_key(mut data: Vec<(u8, u8)>) -> bool {
+    let mut data_copy = data.clone();
+    merge_sort_by_key(&mut data, |&(key, _)| key % 8);
+    data_copy.sort_by_key(|&(key, _)| key % 8);
+    data == data_copy
+}
+
+#[quickcheck]
+fn insertion_thresholds(data: Vec<i32>, insertion_threshold: u8) -> bool {
+    let mut data_copy = data.clone();
+    data_copy.sort_unstable();
+
+    let config = MergeSortConfig {
+        insertion_threshold: insertion_threshold.into(),
+    };
+    let mut data = data;
+    merge_sort_with_config(&mut data, config);
+    data == data_copy
+}
+
+#[test]
+fn tuning() {
+    let sample: Vec<i32> = (0..100).rev().collect();
+    let config = MergeSortConfig::tuned(&sample);
+    assert!((1..=64).contains(&config.insertion_threshold));
+}
+
 // Edge case(s)
 
 #[test]
@@ -1,4 +1,14 @@
+pub mod binary_insertion_sort;
 pub mod bubble_sort;
+pub mod bucket_sort;
+pub mod counting_sort;
+pub mod external_sort;
+pub mod fuzzy_sort;
+pub mod heap_sort;
 pub mod insertion_sort;
+pub mod k_way_merge;
 pub mod merge_sort;
+pub mod quick_sort;
+pub mod radix_sort;
 pub mod selection_sort;
+pub mod sorter;
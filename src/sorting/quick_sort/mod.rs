@@ -0,0 +1,515 @@
+/*! # Chapter 7 - Quicksort
+
+ **Input:** A sequence of `n` elements of a partial order `(a_1, a_2, ..., a_n)`
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(n^2)`, but `O(n lg n)` expected for the randomized variants
+
+ Quicksort is another divide-and-conquer algorithm, but where merge sort does all its work when
+ recombining the subproblems, quicksort does it when dividing: it partitions the array around a
+ pivot element, such that everything to the left of the pivot is at most as large and everything
+ to the right at least as large. Sorting both sides recursively then sorts the whole array, in
+ place and without any recombination step.
+
+ The worst case of `O(n^2)` occurs when the partitions are maximally unbalanced, which happens for
+ the plain version on already sorted input. Picking the pivot at random makes this vanishingly
+ unlikely, and the expected running time is `O(n lg n)` with small constant factors, which is why
+ quicksort is usually the fastest comparison sort in practice.
+
+ This module contains all the variants from the chapter and its problems:
+  * `quick_sort` with the Lomuto `partition` from the text,
+  * `hoare_quick_sort` with the original `hoare_partition` (problem 7-1),
+  * `randomized_quick_sort` with `randomized_partition`,
+  * `three_way_quick_sort` with `three_way_partition`, which copes with equal keys (problem 7-2),
+  * `tail_recursive_quick_sort`, whose stack depth is bounded by `O(lg n)` (problem 7-4), and
+  * `median_of_three_quick_sort` with `median_of_three_partition` (problem 7-5).
+*/
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::util::{cmp::partial_compare, random::Random, slice};
+
+#[cfg(test)]
+mod test;
+
+/// PARTITION: Partition `data` around its last element, returning the index the pivot ends up at.
+/// Afterwards, all elements to the left of the pivot are at most as large as it, and all elements
+/// to its right are larger.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn partition<T>(data: &mut [T]) -> usize
+where
+    T: PartialOrd,
+{
+    partition_by(data, partial_compare)
+}
+
+/// Like `partition`, but in the order defined by `compare`.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn partition_by<T, F>(data: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(!data.is_empty(), "Cannot partition an empty slice");
+
+    let r = data.len() - 1;
+
+    // `data[..i]` holds the elements found to be at most as large as the pivot so far, and
+    // `data[i..j]` those larger than it.
+    let mut i = 0;
+    for j in 0..r {
+        if compare(&data[j], &data[r]) != Ordering::Greater {
+            slice::swap(data, i, j);
+            i += 1;
+        }
+    }
+
+    slice::swap(data, i, r);
+    i
+}
+
+/// HOARE-PARTITION: Partition `data` around its first element, returning an index `j` such that
+/// every element of `data[..=j]` is at most as large as every element of `data[j + 1..]`. Unlike
+/// `partition`, this does not put the pivot into its final place, but `j` is guaranteed to be
+/// smaller than `data.len() - 1` so that neither side is empty (for more than one element).
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn hoare_partition<T>(data: &mut [T]) -> usize
+where
+    T: PartialOrd,
+{
+    hoare_partition_by(data, partial_compare)
+}
+
+/// Like `hoare_partition`, but in the order defined by `compare`.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn hoare_partition_by<T, F>(data: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(!data.is_empty(), "Cannot partition an empty slice");
+
+    // The book copies the pivot into a variable `x`. We don't want to require `Clone`, so we keep
+    // track of where the pivot currently is instead; it moves at most once per swap.
+    let mut pivot = 0;
+    let mut i = 0;
+    let mut j = data.len();
+    let mut first = true;
+
+    loop {
+        j -= 1;
+        while compare(&data[j], &data[pivot]) == Ordering::Greater {
+            j -= 1;
+        }
+
+        if !first {
+            i += 1;
+        }
+        first = false;
+        while compare(&data[i], &data[pivot]) == Ordering::Less {
+            i += 1;
+        }
+
+        if i >= j {
+            return j;
+        }
+
+        slice::swap(data, i, j);
+        if pivot == i {
+            pivot = j;
+        } else if pivot == j {
+            pivot = i;
+        }
+    }
+}
+
+/// RANDOMIZED-PARTITION: Like `partition`, but around an element chosen uniformly at random.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn randomized_partition<T>(data: &mut [T]) -> usize
+where
+    T: PartialOrd,
+{
+    randomized_partition_by(data, partial_compare)
+}
+
+/// Like `randomized_partition`, but in the order defined by `compare`.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn randomized_partition_by<T, F>(data: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    randomized_partition_with(data, &mut Random::new(), &mut compare)
+}
+
+/// PARTITION' from problem 7-2: Partition `data` around its last element into three parts,
+/// returning the range `q..t` of elements equal to the pivot. Afterwards, all elements of
+/// `data[..q]` are smaller than the pivot and all elements of `data[t..]` larger.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn three_way_partition<T>(data: &mut [T]) -> Range<usize>
+where
+    T: PartialOrd,
+{
+    three_way_partition_by(data, partial_compare)
+}
+
+/// Like `three_way_partition`, but in the order defined by `compare`.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn three_way_partition_by<T, F>(data: &mut [T], mut compare: F) -> Range<usize>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(!data.is_empty(), "Cannot partition an empty slice");
+
+    let r = data.len() - 1;
+
+    // We maintain `data[..lt] < pivot`, `data[lt..i] == pivot` and `data[gt..r] > pivot`, while
+    // `data[i..gt]` is yet to be looked at. The pivot itself stays put at `r` until the very end.
+    let mut lt = 0;
+    let mut i = 0;
+    let mut gt = r;
+
+    while i < gt {
+        match compare(&data[i], &data[r]) {
+            Ordering::Less => {
+                slice::swap(data, lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                slice::swap(data, i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+
+    slice::swap(data, gt, r);
+    lt..(gt + 1)
+}
+
+/// Partition `data` around the median of three elements chosen at random, as in `partition`. For
+/// fewer than three elements, this is just `randomized_partition`.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn median_of_three_partition<T>(data: &mut [T]) -> usize
+where
+    T: PartialOrd,
+{
+    median_of_three_partition_by(data, partial_compare)
+}
+
+/// Like `median_of_three_partition`, but in the order defined by `compare`.
+///
+/// ## Panics
+/// This function panics if `data` is empty.
+pub fn median_of_three_partition_by<T, F>(data: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    median_of_three_partition_with(data, &mut Random::new(), &mut compare)
+}
+
+pub fn quick_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    quick_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn quick_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn quick_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_recursion(data, &mut compare);
+}
+
+pub fn hoare_quick_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    hoare_quick_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements, as in `hoare_quick_sort`.
+pub fn hoare_quick_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    hoare_quick_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`, as in `hoare_quick_sort`.
+pub fn hoare_quick_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    hoare_quick_sort_recursion(data, &mut compare);
+}
+
+pub fn randomized_quick_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    randomized_quick_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements, as in `randomized_quick_sort`.
+pub fn randomized_quick_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    randomized_quick_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`, as in `randomized_quick_sort`.
+pub fn randomized_quick_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    randomized_quick_sort_recursion(data, &mut Random::new(), &mut compare);
+}
+
+pub fn three_way_quick_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    three_way_quick_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements, as in `three_way_quick_sort`.
+pub fn three_way_quick_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    three_way_quick_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`, as in `three_way_quick_sort`.
+pub fn three_way_quick_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    three_way_quick_sort_recursion(data, &mut Random::new(), &mut compare);
+}
+
+pub fn tail_recursive_quick_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    tail_recursive_quick_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements, as in `tail_recursive_quick_sort`.
+pub fn tail_recursive_quick_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    tail_recursive_quick_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`, as in `tail_recursive_quick_sort`.
+pub fn tail_recursive_quick_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    tail_recursive_quick_sort_recursion(data, &mut compare);
+}
+
+pub fn median_of_three_quick_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    median_of_three_quick_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements, as in `median_of_three_quick_sort`.
+pub fn median_of_three_quick_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    median_of_three_quick_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`, as in `median_of_three_quick_sort`.
+pub fn median_of_three_quick_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    median_of_three_quick_sort_recursion(data, &mut Random::new(), &mut compare);
+}
+
+// The recursive variants share one random number generator per sort, rather than seeding a new
+// one on every partitioning step.
+pub(crate) fn randomized_partition_with<T, F>(
+    data: &mut [T],
+    random: &mut Random,
+    compare: &mut F,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(!data.is_empty(), "Cannot partition an empty slice");
+
+    let i = random.range(0..data.len());
+    let r = data.len() - 1;
+    slice::swap(data, i, r);
+
+    partition_by(data, compare)
+}
+
+fn median_of_three_partition_with<T, F>(
+    data: &mut [T],
+    random: &mut Random,
+    compare: &mut F,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() < 3 {
+        return randomized_partition_with(data, random, compare);
+    }
+
+    let a = random.range(0..data.len());
+    let b = random.range(0..data.len());
+    let c = random.range(0..data.len());
+
+    let mut at_most = |x: usize, y: usize| compare(&data[x], &data[y]) != Ordering::Greater;
+    let median = if at_most(a, b) == at_most(b, c) {
+        b
+    } else if at_most(b, a) == at_most(a, c) {
+        a
+    } else {
+        c
+    };
+
+    let r = data.len() - 1;
+    slice::swap(data, median, r);
+
+    partition_by(data, compare)
+}
+
+// This implementation is pretty much what is given in the book.
+fn quick_sort_recursion<T, F>(data: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    let q = partition_by(data, &mut *compare);
+    quick_sort_recursion(&mut data[..q], compare);
+    quick_sort_recursion(&mut data[q + 1..], compare);
+}
+
+fn hoare_quick_sort_recursion<T, F>(data: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    let q = hoare_partition_by(data, &mut *compare);
+    hoare_quick_sort_recursion(&mut data[..=q], compare);
+    hoare_quick_sort_recursion(&mut data[q + 1..], compare);
+}
+
+fn tail_recursive_quick_sort_recursion<T, F>(mut data: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // The book's TAIL-RECURSIVE-QUICKSORT replaces the second recursive call by a loop. Doing so
+    // alone still allows a stack depth of `Θ(n)`, though; to bound it by `O(lg n)`, we have to
+    // make sure to only ever recurse into the smaller side, which has at most half the elements.
+    while data.len() > 1 {
+        let q = partition_by(data, &mut *compare);
+        let (left, right) = data.split_at_mut(q);
+        let right = &mut right[1..];
+
+        if left.len() < right.len() {
+            tail_recursive_quick_sort_recursion(left, compare);
+            data = right;
+        } else {
+            tail_recursive_quick_sort_recursion(right, compare);
+            data = left;
+        }
+    }
+}
+
+fn randomized_quick_sort_recursion<T, F>(data: &mut [T], random: &mut Random, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    let q = randomized_partition_with(data, random, compare);
+    randomized_quick_sort_recursion(&mut data[..q], random, compare);
+    randomized_quick_sort_recursion(&mut data[q + 1..], random, compare);
+}
+
+fn three_way_quick_sort_recursion<T, F>(data: &mut [T], random: &mut Random, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    let i = random.range(0..data.len());
+    let r = data.len() - 1;
+    slice::swap(data, i, r);
+
+    // Elements equal to the pivot are already where they belong, so we only have to recurse into
+    // the strictly smaller and larger parts. With many equal keys, this saves a lot of work.
+    let Range { start, end } = three_way_partition_by(data, &mut *compare);
+    three_way_quick_sort_recursion(&mut data[..start], random, compare);
+    three_way_quick_sort_recursion(&mut data[end..], random, compare);
+}
+
+fn median_of_three_quick_sort_recursion<T, F>(data: &mut [T], random: &mut Random, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if data.len() <= 1 {
+        return;
+    }
+
+    let q = median_of_three_partition_with(data, random, compare);
+    median_of_three_quick_sort_recursion(&mut data[..q], random, compare);
+    median_of_three_quick_sort_recursion(&mut data[q + 1..], random, compare);
+}
@@ -0,0 +1,166 @@
+use quickcheck::TestResult;
+use quickcheck_macros::quickcheck;
+
+use super::{
+    hoare_partition, hoare_quick_sort, median_of_three_partition, median_of_three_quick_sort,
+    partition, quick_sort, randomized_partition, randomized_quick_sort, tail_recursive_quick_sort,
+    three_way_partition, three_way_quick_sort, three_way_quick_sort_by,
+    three_way_quick_sort_by_key,
+};
+
+type SortFn = fn(&mut [i32]);
+
+#[derive(Clone, Copy)]
+struct Algorithm<'a> {
+    algorithm: SortFn,
+    name: &'a str,
+}
+
+const ALGORITHMS: [Algorithm; 6] = [
+    Algorithm {
+        algorithm: quick_sort,
+        name: "quick_sort",
+    },
+    Algorithm {
+        algorithm: hoare_quick_sort,
+        name: "hoare_quick_sort",
+    },
+    Algorithm {
+        algorithm: randomized_quick_sort,
+        name: "randomized_quick_sort",
+    },
+    Algorithm {
+        algorithm: three_way_quick_sort,
+        name: "three_way_quick_sort",
+    },
+    Algorithm {
+        algorithm: tail_recursive_quick_sort,
+        name: "tail_recursive_quick_sort",
+    },
+    Algorithm {
+        algorithm: median_of_three_quick_sort,
+        name: "median_of_three_quick_sort",
+    },
+];
+
+fn is_partitioned_at(data: &[i32], q: usize) -> bool {
+    data[..q].iter().all(|d| d <= &data[q]) && data[q + 1..].iter().all(|d| d >= &data[q])
+}
+
+#[quickcheck]
+fn sortedness(data: Vec<i32>) -> bool {
+    ALGORITHMS.iter().all(|a| {
+        let mut data = data.clone();
+        (a.algorithm)(&mut data);
+        data.is_sorted()
+    })
+}
+
+#[quickcheck]
+fn sortedness_preservation(data: Vec<i32>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    ALGORITHMS.iter().all(|a| {
+        let mut data = data.clone();
+        (a.algorithm)(&mut data);
+        data == expected
+    })
+}
+
+// Lots of duplicates are the bane of the two-way partitioning schemes.
+#[quickcheck]
+fn few_distinct_keys(data: Vec<u8>) -> bool {
+    let data: Vec<_> = data.into_iter().map(|d| (d % 3) as i32).collect();
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    ALGORITHMS.iter().all(|a| {
+        let mut data = data.clone();
+        (a.algorithm)(&mut data);
+        data == expected
+    })
+}
+
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    three_way_quick_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn sortedness_by_key(mut data: Vec<(u8, u8)>) -> bool {
+    three_way_quick_sort_by_key(&mut data, |&(key, _)| key % 8);
+    data.windows(2).all(|w| w[0].0 % 8 <= w[1].0 % 8)
+}
+
+#[quickcheck]
+fn lomuto_partitioning(mut data: Vec<i32>) -> TestResult {
+    if data.is_empty() {
+        return TestResult::discard();
+    }
+
+    let pivot = *data.last().unwrap();
+    let q = partition(&mut data);
+    TestResult::from_bool(data[q] == pivot && is_partitioned_at(&data, q))
+}
+
+#[quickcheck]
+fn randomized_partitioning(mut data: Vec<i32>) -> TestResult {
+    if data.is_empty() {
+        return TestResult::discard();
+    }
+
+    let mut other = data.clone();
+    let q = randomized_partition(&mut data);
+    let r = median_of_three_partition(&mut other);
+    TestResult::from_bool(is_partitioned_at(&data, q) && is_partitioned_at(&other, r))
+}
+
+#[quickcheck]
+fn hoare_partitioning(mut data: Vec<i32>) -> TestResult {
+    if data.len() < 2 {
+        return TestResult::discard();
+    }
+
+    let j = hoare_partition(&mut data);
+    let (left, right) = data.split_at(j + 1);
+    TestResult::from_bool(j < data.len() - 1 && left.iter().all(|l| right.iter().all(|r| l <= r)))
+}
+
+#[quickcheck]
+fn three_way_partitioning(data: Vec<u8>) -> TestResult {
+    if data.is_empty() {
+        return TestResult::discard();
+    }
+
+    let mut data: Vec<_> = data.into_iter().map(|d| d % 4).collect();
+    let pivot = *data.last().unwrap();
+    let equal = three_way_partition(&mut data);
+
+    TestResult::from_bool(
+        data[..equal.start].iter().all(|&d| d < pivot)
+            && data[equal.clone()].iter().all(|&d| d == pivot)
+            && data[equal.end..].iter().all(|&d| d > pivot),
+    )
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    for a in ALGORITHMS.iter() {
+        let mut nothing: Vec<i32> = Vec::new();
+        (a.algorithm)(&mut nothing);
+        assert!(nothing.is_empty(), "{} fails on empty slices!", a.name);
+    }
+}
+
+#[test]
+fn sorted_input() {
+    // Sorted input is the worst case for `partition`, in which the plain `quick_sort` recurses `n`
+    // levels deep. The tail-recursive variant only ever goes one level deep here.
+    let mut data: Vec<_> = (0..3_000).collect();
+    tail_recursive_quick_sort(&mut data);
+    assert!(data.is_sorted());
+}
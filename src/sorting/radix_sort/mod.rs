@@ -0,0 +1,263 @@
+/*! # Chapter 8.3 - Radix Sort
+
+ **Input:** A sequence of `n` elements `(a_1, a_2, ..., a_n)`, each consisting of up to `d` digits
+ with `k` possible values each
+
+ **Output:** A permutation `(a'_1, a'_2, ..., a'_n)` of the input sequence such that `a'_1 <= a'_2
+ <= ... <= a'_n`
+
+ **Time complexity:** `O(d(n + k))`
+
+ Radix sort sorts its input digit by digit, starting with the _least_ significant one. That seems
+ backwards at first, but since every pass uses a stable sort (in our case, `counting_sort`), ties
+ in the current digit are broken by the order established by the less significant digits before.
+ For keys of a fixed number of digits, such as machine integers, this sorts in linear time.
+
+ What a digit is depends on the type, so radix sort is driven by the `Radix` trait, which is
+ implemented for all primitive integers, byte strings and tuples of up to four elements. Integers
+ are split into digits of a configurable number of bits; wider digits mean fewer passes, but larger
+ counting arrays. Signed integers get their sign bit flipped, which makes their two's complement
+ representation sort correctly. Byte strings always use whole bytes as digits, and are padded at
+ the end with a digit smaller than any byte, so that prefixes sort first. Tuples are sorted by
+ their components from the last to the first, which is radix sort all over again.
+*/
+use crate::{sorting::counting_sort::counting_sort, util::slice};
+
+#[cfg(test)]
+mod test;
+
+/// The digit width in bits used by `radix_sort`.
+pub const DEFAULT_DIGIT_WIDTH: u32 = 8;
+
+/// The largest supported digit width in bits. Counting sort needs an array of `2^width` counters,
+/// so anything wider is impractical.
+pub const MAX_DIGIT_WIDTH: u32 = 16;
+
+/// A type that can be split into digits, from the most significant to the least significant one.
+pub trait Radix {
+    /// The number of possible digit values, i.e. every digit lies in `0..Self::radix(width)`.
+    fn radix(width: u32) -> usize;
+
+    /// The number of digits `self` has, for digits of `width` bits.
+    fn digit_count(&self, width: u32) -> usize;
+
+    /// The `index`-th most significant digit of `self`, for digits of `width` bits. For `index` at
+    /// or beyond `digit_count`, this is zero.
+    fn digit(&self, index: usize, width: u32) -> usize;
+
+    /// Stably sort `order`, a sequence of indices into `keys`, by the keys the indices refer to.
+    /// This runs counting sort on one digit after another, least significant first. Composite
+    /// types override it to sort by each of their components in turn.
+    fn sort_indices(keys: &[&Self], order: &mut [usize], width: u32) {
+        let digits = keys.iter().map(|k| k.digit_count(width)).max().unwrap_or(0);
+
+        for i in (0..digits).rev() {
+            counting_sort(order, Self::radix(width), |&j| keys[j].digit(i, width));
+        }
+    }
+}
+
+pub fn radix_sort<T>(data: &mut [T])
+where
+    T: Radix,
+{
+    radix_sort_with_width(data, DEFAULT_DIGIT_WIDTH);
+}
+
+/// Radix sort `data`, using digits of `width` bits.
+///
+/// ## Panics
+/// This function panics if `width` is zero or larger than `MAX_DIGIT_WIDTH`.
+pub fn radix_sort_with_width<T>(data: &mut [T], width: u32)
+where
+    T: Radix,
+{
+    radix_sort_by_key(data, width, |d| d);
+}
+
+/// Stably radix sort `data` by the keys `key` projects out of its elements, using digits of `width`
+/// bits.
+///
+/// ## Panics
+/// This function panics if `width` is zero or larger than `MAX_DIGIT_WIDTH`.
+pub fn radix_sort_by_key<T, K, F>(data: &mut [T], width: u32, key: F)
+where
+    K: Radix + ?Sized,
+    F: Fn(&T) -> &K,
+{
+    assert!(
+        (1..=MAX_DIGIT_WIDTH).contains(&width),
+        "Digit width must lie in 1..={}, got {}",
+        MAX_DIGIT_WIDTH,
+        width
+    );
+
+    // Rather than moving the elements around in every pass, we sort their indices, and only move
+    // the elements into place once we know where they go.
+    let keys: Vec<&K> = data.iter().map(key).collect();
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    K::sort_indices(&keys, &mut order, width);
+
+    let mut destinations = vec![0; data.len()];
+    for (position, &index) in order.iter().enumerate() {
+        destinations[index] = position;
+    }
+
+    slice::permute(data, &mut destinations);
+}
+
+macro_rules! impl_radix_for_integer {
+    ($($t:ty => $unsigned:ty, $sign:expr);* $(;)?) => {
+        $(
+            impl Radix for $t {
+                fn radix(width: u32) -> usize {
+                    1 << width
+                }
+
+                fn digit_count(&self, width: u32) -> usize {
+                    <$t>::BITS.div_ceil(width) as usize
+                }
+
+                fn digit(&self, index: usize, width: u32) -> usize {
+                    let digits = self.digit_count(width);
+                    if index >= digits {
+                        return 0;
+                    }
+
+                    let bits = (*self as $unsigned ^ $sign) as u128;
+                    let shift = (digits - 1 - index) as u32 * width;
+                    ((bits >> shift) & ((1 << width) - 1)) as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_for_integer! {
+    u8 => u8, 0;
+    u16 => u16, 0;
+    u32 => u32, 0;
+    u64 => u64, 0;
+    u128 => u128, 0;
+    usize => usize, 0;
+    i8 => u8, 1 << (u8::BITS - 1);
+    i16 => u16, 1 << (u16::BITS - 1);
+    i32 => u32, 1 << (u32::BITS - 1);
+    i64 => u64, 1 << (u64::BITS - 1);
+    i128 => u128, 1 << (u128::BITS - 1);
+    isize => usize, 1 << (usize::BITS - 1);
+}
+
+impl Radix for [u8] {
+    fn radix(_width: u32) -> usize {
+        // Every byte value, plus one for the padding past the end of the string.
+        u8::MAX as usize + 2
+    }
+
+    fn digit_count(&self, _width: u32) -> usize {
+        self.len()
+    }
+
+    fn digit(&self, index: usize, _width: u32) -> usize {
+        match self.get(index) {
+            Some(&byte) => byte as usize + 1,
+            None => 0,
+        }
+    }
+}
+
+// Comparing the UTF-8 encodings of two strings byte by byte agrees with comparing the strings.
+macro_rules! impl_radix_for_byte_string {
+    ($($t:ty),*) => {
+        $(
+            impl Radix for $t {
+                fn radix(width: u32) -> usize {
+                    <[u8]>::radix(width)
+                }
+
+                fn digit_count(&self, width: u32) -> usize {
+                    <[u8]>::digit_count(self.as_ref(), width)
+                }
+
+                fn digit(&self, index: usize, width: u32) -> usize {
+                    <[u8]>::digit(self.as_ref(), index, width)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_for_byte_string!(str, String, Vec<u8>);
+
+impl<R> Radix for &R
+where
+    R: Radix + ?Sized,
+{
+    fn radix(width: u32) -> usize {
+        R::radix(width)
+    }
+
+    fn digit_count(&self, width: u32) -> usize {
+        (**self).digit_count(width)
+    }
+
+    fn digit(&self, index: usize, width: u32) -> usize {
+        (**self).digit(index, width)
+    }
+
+    fn sort_indices(keys: &[&Self], order: &mut [usize], width: u32) {
+        let keys: Vec<&R> = keys.iter().map(|k| &***k).collect();
+        R::sort_indices(&keys, order, width);
+    }
+}
+
+// The digits of a tuple are those of its components, one after another. That is only a faithful
+// representation of the tuple's order if every component has the same number of digits in every
+// element, so sorting works component by component instead, from the last to the first.
+macro_rules! impl_radix_for_tuple {
+    ($(($($t:ident $i:tt),+))*) => {
+        $(
+            impl<$($t: Radix),+> Radix for ($($t,)+) {
+                fn radix(width: u32) -> usize {
+                    0 $(.max($t::radix(width)))+
+                }
+
+                fn digit_count(&self, width: u32) -> usize {
+                    0 $(+ self.$i.digit_count(width))+
+                }
+
+                fn digit(&self, mut index: usize, width: u32) -> usize {
+                    $(
+                        let digits = self.$i.digit_count(width);
+                        if index < digits {
+                            return self.$i.digit(index, width);
+                        }
+                        index -= digits;
+                    )+
+
+                    let _ = index;
+                    0
+                }
+
+                fn sort_indices(keys: &[&Self], order: &mut [usize], width: u32) {
+                    impl_radix_for_tuple!(@reverse keys, order, width; $($t $i),+);
+                }
+            }
+        )*
+    };
+
+    // Emit the sorting passes for the components in reverse order.
+    (@reverse $keys:ident, $order:ident, $width:ident; $t:ident $i:tt $(, $rest:ident $j:tt)*) => {
+        impl_radix_for_tuple!(@reverse $keys, $order, $width; $($rest $j),*);
+        let component: Vec<&$t> = $keys.iter().map(|k| &k.$i).collect();
+        $t::sort_indices(&component, $order, $width);
+    };
+    (@reverse $keys:ident, $order:ident, $width:ident;) => {};
+}
+
+impl_radix_for_tuple! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+}
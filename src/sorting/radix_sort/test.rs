@@ -0,0 +1,98 @@
+use quickcheck_macros::quickcheck;
+
+use super::{radix_sort, radix_sort_by_key, radix_sort_with_width, Radix};
+
+#[quickcheck]
+fn sortedness(mut data: Vec<u64>) -> bool {
+    radix_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<u64>) -> bool {
+    let mut data_copy = data.clone();
+    radix_sort(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn signed_integers(mut data: Vec<i32>, mut small: Vec<i8>, mut large: Vec<i128>) -> bool {
+    let mut expected = (data.clone(), small.clone(), large.clone());
+    expected.0.sort_unstable();
+    expected.1.sort_unstable();
+    expected.2.sort_unstable();
+
+    radix_sort(&mut data);
+    radix_sort(&mut small);
+    radix_sort(&mut large);
+
+    (data, small, large) == expected
+}
+
+#[quickcheck]
+fn uneven_digit_widths(mut data: Vec<i16>) -> bool {
+    let mut data_copy = data.clone();
+    data_copy.sort_unstable();
+
+    // Neither width divides the 16 bits evenly.
+    let mut wide = data.clone();
+    radix_sort_with_width(&mut data, 3);
+    radix_sort_with_width(&mut wide, 11);
+
+    data == data_copy && wide == data_copy
+}
+
+#[quickcheck]
+fn byte_strings(mut strings: Vec<String>, mut bytes: Vec<Vec<u8>>) -> bool {
+    let mut expected = (strings.clone(), bytes.clone());
+    expected.0.sort_unstable();
+    expected.1.sort_unstable();
+
+    radix_sort(&mut strings);
+    radix_sort(&mut bytes);
+
+    (strings, bytes) == expected
+}
+
+#[quickcheck]
+fn tuples(mut data: Vec<(u8, String, i64)>) -> bool {
+    let mut data_copy = data.clone();
+    radix_sort(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn stability(keys: Vec<u8>) -> bool {
+    let mut data: Vec<_> = keys.into_iter().enumerate().map(|(i, k)| (k, i)).collect();
+    radix_sort_by_key(&mut data, 4, |(key, _)| key);
+
+    data.windows(2)
+        .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1))
+}
+
+#[test]
+fn digits() {
+    assert_eq!(0x1234u16.digit_count(4), 4);
+    assert_eq!(0x1234u16.digit(1, 4), 0x2);
+    assert_eq!((-1i8).digit(0, 8), 0x7f);
+    assert_eq!("ab".digit(1, 8), b'b' as usize + 1);
+    assert_eq!("ab".digit(2, 8), 0);
+    assert_eq!((0x12u8, 0x34u8).digit(1, 8), 0x34);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<u32> = Vec::new();
+    radix_sort(&mut nothing);
+    assert!(nothing.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn zero_width() {
+    radix_sort_with_width(&mut [3u32, 2, 1], 0);
+}
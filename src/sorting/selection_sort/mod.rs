@@ -10,19 +10,22 @@
  Selection sort is another simple sorting algorithm with suboptimal time complexity, based around
  the idea of finding the next smallest element in each iteration. 
 */
+use std::cmp::Ordering;
+
+use crate::util::{cmp::partial_compare, slice};
 
 #[cfg(test)]
 mod test;
 
 // A simple function to find the index of the minimum of a given slice. I think this function
 // doesn't exist in the standard library, but I might be wrong.
-fn min_index<T>(data: &[T]) -> usize
+fn min_index<T, F>(data: &[T], compare: &mut F) -> usize
 where
-    T: PartialOrd,
+    F: FnMut(&T, &T) -> Ordering,
 {
     let mut min_index = 0;
     for i in 0..data.len() {
-        if data[i] < data[min_index] {
+        if compare(&data[i], &data[min_index]) == Ordering::Less {
             min_index = i;
         }
     }
@@ -33,6 +36,23 @@ where
 pub fn selection_sort<T>(data: &mut [T])
 where
     T: PartialOrd,
+{
+    selection_sort_by(data, partial_compare);
+}
+
+/// Sort `data` by the keys `key` extracts from its elements.
+pub fn selection_sort_by_key<T, K, F>(data: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    selection_sort_by(data, |a, b| partial_compare(&key(a), &key(b)));
+}
+
+/// Sort `data` into the order defined by `compare`.
+pub fn selection_sort_by<T, F>(data: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
 {
     if data.len() <= 1 {
         return;
@@ -40,7 +60,7 @@ where
 
     for i in 0..(data.len() - 1) {
         // Do not forget to add `i`, as the result of `min_index` is relative to the passed slice
-        let index = i + min_index(&data[i..]);
-        data.swap(i, index);
+        let index = i + min_index(&data[i..], &mut compare);
+        slice::swap(data, i, index);
     }
 }
@@ -1,6 +1,6 @@
 use quickcheck_macros::quickcheck;
 
-use super::selection_sort;
+use super::{selection_sort, selection_sort_by, selection_sort_by_key};
 
 // If only `is_sorted` was already stabilized...
 fn is_sorted<T: PartialOrd>(data: &Vec<T>) -> bool {
@@ -31,6 +31,18 @@ fn sortedness_preservation(mut data: Vec<i32>) -> bool {
     data == data_copy
 }
 
+#[quickcheck]
+fn descending_order(mut data: Vec<i32>) -> bool {
+    selection_sort_by(&mut data, |a, b| b.cmp(a));
+    data.windows(2).all(|w| w[0] >= w[1])
+}
+
+#[quickcheck]
+fn sortedness_by_key(mut data: Vec<(u8, u8)>) -> bool {
+    selection_sort_by_key(&mut data, |&(key, _)| key % 8);
+    data.windows(2).all(|w| w[0].0 % 8 <= w[1].0 % 8)
+}
+
 // Edge case(s)
 
 #[test]
@@ -0,0 +1,380 @@
+/*! # Sorters
+
+ The sorting algorithms of this crate are plain functions with all sorts of different trait bounds,
+ which is fine for calling them directly, but makes it awkward to choose one at runtime, or to run
+ the same test against all of them. The `Sorter` trait puts a common face on them: every algorithm
+ in `sorting` that sorts a slice in memory is represented by a unit struct implementing `Sorter<T>`
+ for the element types it supports, along with `Properties` describing it.
+
+ A `Registry` collects sorters and looks them up by name. `Registry::non_cloning_sorts` contains
+ the comparison sorts that work for any `PartialOrd` type, moving the elements around without
+ cloning them. `Registry::comparison_sorts` adds the merge sorts that need `Clone` as well, and
+ `Send` for the parallel one, thus containing all comparison sorts. The sorters restricted to
+ particular types (counting, radix and bucket sort) and the fuzzy sort of intervals can be added
+ with `Registry::register`. The external merge sort and the k-way merge don't sort slices, and
+ thus have no sorters.
+*/
+use std::fmt;
+
+use super::{
+    binary_insertion_sort::binary_insertion_sort,
+    bubble_sort::bubble_sort,
+    bucket_sort::{bucket_sort, UnitInterval},
+    counting_sort::counting_sort,
+    fuzzy_sort::{fuzzy_sort, Interval},
+    heap_sort::{d_ary::d_ary_heap_sort, heap_sort},
+    insertion_sort::insertion_sort,
+    merge_sort::{
+        bottom_up::bottom_up_merge_sort, indirect::indirect_merge_sort, merge_sort,
+        parallel::parallel_merge_sort,
+    },
+    quick_sort::{
+        hoare_quick_sort, median_of_three_quick_sort, quick_sort, randomized_quick_sort,
+        tail_recursive_quick_sort, three_way_quick_sort,
+    },
+    radix_sort::{radix_sort, Radix},
+    selection_sort::selection_sort,
+};
+
+#[cfg(test)]
+mod test;
+
+/// What there is to know about a sorting algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Properties {
+    /// The name to look the algorithm up by, which is also the name of its function.
+    pub name: &'static str,
+    /// Where the algorithm appears in the book.
+    pub section: &'static str,
+    /// Whether equal elements keep their relative order.
+    pub stable: bool,
+    /// Whether the algorithm gets by with `O(lg n)` additional memory.
+    pub in_place: bool,
+    /// The running time in the best case.
+    pub best: &'static str,
+    /// The (expected) running time in the average case.
+    pub average: &'static str,
+    /// The running time in the worst case.
+    pub worst: &'static str,
+}
+
+/// A sorting algorithm for slices of `T`.
+pub trait Sorter<T> {
+    /// The properties of the algorithm.
+    fn properties(&self) -> Properties;
+
+    /// Sort `data` in increasing order.
+    ///
+    /// ## Panics
+    /// This function panics if the algorithm doesn't support some of the values in `data`, as
+    /// `BucketSort` does for values outside of `[0, 1)`.
+    fn sort(&self, data: &mut [T]);
+
+    /// The name of the algorithm, as given by its properties.
+    fn name(&self) -> &'static str {
+        self.properties().name
+    }
+}
+
+// Define a unit struct for the sorting function `$sort`, with the given bounds on the element type
+// and properties. The sorter is named after the function, so the two can't disagree.
+macro_rules! sorter {
+    (
+        $(#[$attribute:meta])*
+        $sorter:ident, $sort:ident $(::<$($generic:tt),*>)?, [$($bounds:tt)*],
+        $section:literal, stable: $stable:literal, in_place: $in_place:literal,
+        $best:literal, $average:literal, $worst:literal
+    ) => {
+        $(#[$attribute])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $sorter;
+
+        impl $sorter {
+            pub const PROPERTIES: Properties = Properties {
+                name: stringify!($sort),
+                section: $section,
+                stable: $stable,
+                in_place: $in_place,
+                best: $best,
+                average: $average,
+                worst: $worst,
+            };
+        }
+
+        impl<T> Sorter<T> for $sorter
+        where
+            T: $($bounds)*,
+        {
+            fn properties(&self) -> Properties {
+                Self::PROPERTIES
+            }
+
+            fn sort(&self, data: &mut [T]) {
+                $sort$(::<$($generic),*>)?(data);
+            }
+        }
+    };
+}
+
+sorter!(
+    InsertionSort, insertion_sort, [PartialOrd],
+    "2.1", stable: true, in_place: true,
+    "O(n)", "O(n^2)", "O(n^2)"
+);
+sorter!(
+    SelectionSort, selection_sort, [PartialOrd],
+    "Exercise 2.2-2", stable: false, in_place: true,
+    "O(n^2)", "O(n^2)", "O(n^2)"
+);
+sorter!(
+    MergeSort, merge_sort, [PartialOrd + Clone],
+    "2.3", stable: true, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n lg n)"
+);
+sorter!(
+    /// The bottom-up merge sort, see `merge_sort::bottom_up`.
+    BottomUpMergeSort, bottom_up_merge_sort, [PartialOrd + Clone],
+    "2.3", stable: true, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n lg n)"
+);
+sorter!(
+    /// The merge sort without a `Clone` bound, see `merge_sort::indirect`.
+    IndirectMergeSort, indirect_merge_sort, [PartialOrd],
+    "2.3", stable: true, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n lg n)"
+);
+sorter!(
+    BinaryInsertionSort, binary_insertion_sort, [PartialOrd],
+    "Exercise 2.3-6", stable: true, in_place: true,
+    "O(n lg n)", "O(n^2)", "O(n^2)"
+);
+sorter!(
+    BubbleSort, bubble_sort, [PartialOrd],
+    "Problem 2-2", stable: true, in_place: true,
+    "O(n^2)", "O(n^2)", "O(n^2)"
+);
+sorter!(
+    HeapSort, heap_sort, [PartialOrd],
+    "6.4", stable: false, in_place: true,
+    "O(n lg n)", "O(n lg n)", "O(n lg n)"
+);
+sorter!(
+    /// Heapsort with a 4-ary heap, see `heap_sort::d_ary`.
+    DaryHeapSort, d_ary_heap_sort::<T, 4>, [PartialOrd],
+    "Problem 6-2", stable: false, in_place: true,
+    "O(n lg n)", "O(n lg n)", "O(n lg n)"
+);
+sorter!(
+    QuickSort, quick_sort, [PartialOrd],
+    "7.1", stable: false, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n^2)"
+);
+sorter!(
+    RandomizedQuickSort, randomized_quick_sort, [PartialOrd],
+    "7.3", stable: false, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n^2)"
+);
+sorter!(
+    HoareQuickSort, hoare_quick_sort, [PartialOrd],
+    "Problem 7-1", stable: false, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n^2)"
+);
+sorter!(
+    ThreeWayQuickSort, three_way_quick_sort, [PartialOrd],
+    "Problem 7-2", stable: false, in_place: false,
+    "O(n)", "O(n lg n)", "O(n^2)"
+);
+sorter!(
+    TailRecursiveQuickSort, tail_recursive_quick_sort, [PartialOrd],
+    "Problem 7-4", stable: false, in_place: true,
+    "O(n lg n)", "O(n lg n)", "O(n^2)"
+);
+sorter!(
+    MedianOfThreeQuickSort, median_of_three_quick_sort, [PartialOrd],
+    "Problem 7-5", stable: false, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n^2)"
+);
+sorter!(
+    /// Radix sort with the default digit width, so `k` is 256 for integers.
+    RadixSort, radix_sort, [Radix],
+    "8.3", stable: true, in_place: false,
+    "O(d (n + k))", "O(d (n + k))", "O(d (n + k))"
+);
+sorter!(
+    /// Bucket sort, for values in `[0, 1)`.
+    BucketSort, bucket_sort, [PartialOrd + UnitInterval],
+    "8.4", stable: true, in_place: false,
+    "O(n)", "O(n)", "O(n^2)"
+);
+sorter!(
+    ParallelMergeSort, parallel_merge_sort, [PartialOrd + Clone + Send],
+    "27.3", stable: true, in_place: false,
+    "O(n lg n)", "O(n lg n)", "O(n lg n)"
+);
+
+/// Counting sort, keyed by the values themselves, so `k` is 256 for `u8` and 65536 for `u16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CountingSort;
+
+impl CountingSort {
+    pub const PROPERTIES: Properties = Properties {
+        name: "counting_sort",
+        section: "8.2",
+        stable: true,
+        in_place: false,
+        best: "O(n + k)",
+        average: "O(n + k)",
+        worst: "O(n + k)",
+    };
+}
+
+impl Sorter<u8> for CountingSort {
+    fn properties(&self) -> Properties {
+        Self::PROPERTIES
+    }
+
+    fn sort(&self, data: &mut [u8]) {
+        counting_sort(data, 1 << u8::BITS, |&x| x.into());
+    }
+}
+
+impl Sorter<u16> for CountingSort {
+    fn properties(&self) -> Properties {
+        Self::PROPERTIES
+    }
+
+    fn sort(&self, data: &mut [u16]) {
+        counting_sort(data, 1 << u16::BITS, |&x| x.into());
+    }
+}
+
+/// Fuzzy sorting of intervals, which only orders them up to overlaps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FuzzySort;
+
+impl FuzzySort {
+    pub const PROPERTIES: Properties = Properties {
+        name: "fuzzy_sort",
+        section: "Problem 7-6",
+        stable: false,
+        in_place: false,
+        best: "O(n)",
+        average: "O(n lg n)",
+        worst: "O(n^2)",
+    };
+}
+
+impl<T> Sorter<Interval<T>> for FuzzySort
+where
+    T: PartialOrd + Clone,
+{
+    fn properties(&self) -> Properties {
+        Self::PROPERTIES
+    }
+
+    fn sort(&self, data: &mut [Interval<T>]) {
+        fuzzy_sort(data);
+    }
+}
+
+/// A collection of sorters for `T`, to be looked up by name.
+pub struct Registry<T> {
+    sorters: Vec<Box<dyn Sorter<T>>>,
+}
+
+impl<T> Default for Registry<T> {
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+impl<T> fmt::Debug for Registry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl<T> Registry<T> {
+    /// An empty registry.
+    pub fn new() -> Registry<T> {
+        Registry {
+            sorters: Vec::new(),
+        }
+    }
+
+    /// Add `sorter` to the registry, replacing any sorter of the same name.
+    pub fn register<S>(&mut self, sorter: S)
+    where
+        S: Sorter<T> + 'static,
+    {
+        self.sorters.retain(|s| s.name() != sorter.name());
+        self.sorters.push(Box::new(sorter));
+    }
+
+    /// The sorter called `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&dyn Sorter<T>> {
+        self.iter().find(|s| s.name() == name)
+    }
+
+    /// All sorters, in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Sorter<T>> {
+        self.sorters.iter().map(|s| s.as_ref())
+    }
+
+    /// The names of all sorters, in the order they were registered.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.iter().map(|s| s.name())
+    }
+
+    /// The number of sorters.
+    pub fn len(&self) -> usize {
+        self.sorters.len()
+    }
+
+    /// Whether there are no sorters at all.
+    pub fn is_empty(&self) -> bool {
+        self.sorters.is_empty()
+    }
+}
+
+impl<T> Registry<T>
+where
+    T: PartialOrd,
+{
+    /// A registry of the comparison sorts that don't need to clone elements.
+    pub fn non_cloning_sorts() -> Registry<T> {
+        let mut registry = Registry::new();
+
+        registry.register(InsertionSort);
+        registry.register(SelectionSort);
+        registry.register(IndirectMergeSort);
+        registry.register(BinaryInsertionSort);
+        registry.register(BubbleSort);
+        registry.register(HeapSort);
+        registry.register(DaryHeapSort);
+        registry.register(QuickSort);
+        registry.register(RandomizedQuickSort);
+        registry.register(HoareQuickSort);
+        registry.register(ThreeWayQuickSort);
+        registry.register(TailRecursiveQuickSort);
+        registry.register(MedianOfThreeQuickSort);
+
+        registry
+    }
+}
+
+impl<T> Registry<T>
+where
+    T: PartialOrd + Clone + Send,
+{
+    /// A registry of all comparison sorts.
+    pub fn comparison_sorts() -> Registry<T> {
+        let mut registry = Registry::non_cloning_sorts();
+
+        registry.register(MergeSort);
+        registry.register(BottomUpMergeSort);
+        registry.register(ParallelMergeSort);
+
+        registry
+    }
+}
@@ -0,0 +1,282 @@
+use quickcheck_macros::quickcheck;
+
+use super::{
+    BinaryInsertionSort, BottomUpMergeSort, BubbleSort, BucketSort, CountingSort, DaryHeapSort,
+    FuzzySort, HeapSort, HoareQuickSort, IndirectMergeSort, InsertionSort, MedianOfThreeQuickSort,
+    MergeSort, ParallelMergeSort, Properties, QuickSort, RadixSort, RandomizedQuickSort, Registry,
+    SelectionSort, Sorter, TailRecursiveQuickSort, ThreeWayQuickSort,
+};
+use crate::sorting::{
+    fuzzy_sort::Interval,
+    heap_sort::{d_ary::d_ary_heap_sort, heap_sort},
+    merge_sort::merge_sort,
+    quick_sort::quick_sort,
+    radix_sort::radix_sort,
+};
+
+// An element that is ordered by its key only, so that we can tell equal elements apart.
+#[derive(Debug, Clone, Copy)]
+struct Keyed(u8, usize);
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+#[quickcheck]
+fn sortedness_preservation(data: Vec<i32>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    Registry::comparison_sorts().iter().all(|sorter| {
+        let mut data = data.clone();
+        sorter.sort(&mut data);
+        data == expected
+    })
+}
+
+// An element that can't be cloned, for the sorts that don't need to.
+#[derive(Debug, PartialEq, PartialOrd)]
+struct NoClone(i32);
+
+#[quickcheck]
+fn non_cloning_sorts(data: Vec<i32>) -> bool {
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    Registry::non_cloning_sorts().iter().all(|sorter| {
+        let mut data: Vec<_> = data.iter().copied().map(NoClone).collect();
+        sorter.sort(&mut data);
+        data.into_iter().map(|x| x.0).eq(expected.iter().copied())
+    })
+}
+
+// Whether `data` is ordered by key, with equal keys in the order of their payloads.
+fn is_stably_sorted(data: &[Keyed]) -> bool {
+    data.windows(2)
+        .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1))
+}
+
+// Few distinct keys, so that most elements have equal ones, tagged with their original positions.
+fn keyed(keys: impl IntoIterator<Item = u8>) -> Vec<Keyed> {
+    keys.into_iter()
+        .enumerate()
+        .map(|(i, key)| Keyed(key % 4, i))
+        .collect()
+}
+
+#[quickcheck]
+fn stability(keys: Vec<u8>) -> bool {
+    let data = keyed(keys);
+
+    Registry::comparison_sorts()
+        .iter()
+        .filter(|sorter| sorter.properties().stable)
+        .all(|sorter| {
+            let mut data = data.clone();
+            sorter.sort(&mut data);
+            is_stably_sorted(&data)
+        })
+}
+
+// The sorters claiming to be unstable should be caught reordering equal elements, or they might as
+// well claim to be stable.
+#[test]
+fn instability() {
+    let inputs = [
+        keyed((0..64).rev()),
+        keyed((0..64).map(|i| i * 3)),
+        keyed([1; 64]),
+    ];
+
+    for sorter in Registry::comparison_sorts().iter() {
+        let stable = inputs.iter().all(|data| {
+            let mut data = data.clone();
+            sorter.sort(&mut data);
+            is_stably_sorted(&data)
+        });
+        assert_eq!(stable, sorter.properties().stable, "{}", sorter.name());
+    }
+}
+
+#[quickcheck]
+fn non_comparison_sorts(bytes: Vec<u8>, numbers: Vec<u64>) -> bool {
+    let mut expected_bytes = bytes.clone();
+    expected_bytes.sort_unstable();
+    let mut expected_numbers = numbers.clone();
+    expected_numbers.sort_unstable();
+
+    let mut bytes = bytes;
+    let mut numbers = numbers;
+    let mut fractions: Vec<_> = expected_bytes
+        .iter()
+        .rev()
+        .map(|&b| f64::from(b) / 256.0)
+        .collect();
+    let mut intervals: Vec<_> = expected_bytes
+        .iter()
+        .rev()
+        .map(|&b| Interval::new(b, b))
+        .collect();
+
+    CountingSort.sort(&mut bytes);
+    RadixSort.sort(&mut numbers);
+    BucketSort.sort(&mut fractions);
+    FuzzySort.sort(&mut intervals);
+
+    bytes == expected_bytes
+        && numbers == expected_numbers
+        && fractions.is_sorted()
+        && intervals.iter().map(|i| i.start).eq(expected_bytes)
+}
+
+#[test]
+fn lookup() {
+    let registry = Registry::<i32>::comparison_sorts();
+
+    let sorter = registry.get("merge_sort").unwrap();
+    assert_eq!(sorter.properties(), MergeSort::PROPERTIES);
+    assert!(registry.get("bogo_sort").is_none());
+
+    let mut data = [3, 1, 2];
+    registry.get("heap_sort").unwrap().sort(&mut data);
+    assert_eq!(data, [1, 2, 3]);
+}
+
+// Every sorting algorithm needs to be registered here, or it escapes the tests above.
+#[test]
+fn registered_names() {
+    let mut names: Vec<_> = Registry::<i32>::comparison_sorts().names().collect();
+    names.sort_unstable();
+
+    let mut expected = [
+        "binary_insertion_sort",
+        "bottom_up_merge_sort",
+        "bubble_sort",
+        "d_ary_heap_sort",
+        "heap_sort",
+        "hoare_quick_sort",
+        "indirect_merge_sort",
+        "insertion_sort",
+        "median_of_three_quick_sort",
+        "merge_sort",
+        "parallel_merge_sort",
+        "quick_sort",
+        "randomized_quick_sort",
+        "selection_sort",
+        "tail_recursive_quick_sort",
+        "three_way_quick_sort",
+    ];
+    expected.sort_unstable();
+    assert_eq!(names, expected);
+
+    let cloning = ["bottom_up_merge_sort", "merge_sort", "parallel_merge_sort"];
+    let mut non_cloning: Vec<_> = Registry::<i32>::non_cloning_sorts().names().collect();
+    non_cloning.sort_unstable();
+    assert_eq!(
+        non_cloning,
+        expected
+            .into_iter()
+            .filter(|name| !cloning.contains(name))
+            .collect::<Vec<_>>()
+    );
+}
+
+// The registries replace sorters of the same name, so a name shared by two sorters would make one
+// of them vanish silently; check the names before any registering.
+#[test]
+fn unique_names() {
+    let mut names = [
+        InsertionSort::PROPERTIES,
+        SelectionSort::PROPERTIES,
+        MergeSort::PROPERTIES,
+        BottomUpMergeSort::PROPERTIES,
+        IndirectMergeSort::PROPERTIES,
+        BinaryInsertionSort::PROPERTIES,
+        BubbleSort::PROPERTIES,
+        HeapSort::PROPERTIES,
+        DaryHeapSort::PROPERTIES,
+        QuickSort::PROPERTIES,
+        RandomizedQuickSort::PROPERTIES,
+        HoareQuickSort::PROPERTIES,
+        ThreeWayQuickSort::PROPERTIES,
+        TailRecursiveQuickSort::PROPERTIES,
+        MedianOfThreeQuickSort::PROPERTIES,
+        RadixSort::PROPERTIES,
+        BucketSort::PROPERTIES,
+        ParallelMergeSort::PROPERTIES,
+        CountingSort::PROPERTIES,
+        FuzzySort::PROPERTIES,
+    ]
+    .map(|properties| properties.name);
+    let len = names.len();
+    names.sort_unstable();
+
+    assert!(names.windows(2).all(|w| w[0] != w[1]), "{names:?}");
+
+    // All but radix, bucket, counting and fuzzy sort are comparison sorts.
+    assert_eq!(Registry::<i32>::comparison_sorts().len(), len - 4);
+}
+
+// Sorters are named after their functions, which `type_name` spells out for us.
+#[test]
+fn function_names() {
+    fn name_of<F>(_: F) -> &'static str {
+        let path = std::any::type_name::<F>();
+        let path = path.split('<').next().unwrap();
+        path.rsplit("::").next().unwrap()
+    }
+
+    let functions = [
+        (HeapSort::PROPERTIES, name_of(heap_sort::<i32>)),
+        (DaryHeapSort::PROPERTIES, name_of(d_ary_heap_sort::<i32, 4>)),
+        (QuickSort::PROPERTIES, name_of(quick_sort::<i32>)),
+        (MergeSort::PROPERTIES, name_of(merge_sort::<i32>)),
+        (RadixSort::PROPERTIES, name_of(radix_sort::<u64>)),
+    ];
+
+    for (properties, function) in functions {
+        assert_eq!(properties.name, function);
+    }
+}
+
+#[test]
+fn register() {
+    struct Reversed;
+
+    impl Sorter<i32> for Reversed {
+        fn properties(&self) -> Properties {
+            Properties {
+                name: "reversed",
+                section: "-",
+                ..InsertionSort::PROPERTIES
+            }
+        }
+
+        fn sort(&self, data: &mut [i32]) {
+            data.sort_unstable_by(|a, b| b.cmp(a));
+        }
+    }
+
+    let mut registry = Registry::new();
+    assert!(registry.is_empty());
+
+    registry.register(InsertionSort);
+    registry.register(Reversed);
+    registry.register(InsertionSort);
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        ["reversed", "insertion_sort"]
+    );
+
+    let mut data = [1, 3, 2];
+    registry.get("reversed").unwrap().sort(&mut data);
+    assert_eq!(data, [3, 2, 1]);
+}
@@ -0,0 +1,94 @@
+/*! # Chapters 27.3 to 27.5 (2nd edition) - Bitonic Sorting Network
+
+ **Input:** A number of wires `n`
+
+ **Output:** A sorting network on `n` wires
+
+ **Time complexity:** `Θ(n lg^2 n)` comparators with a depth of `Θ(lg^2 n)`
+
+ A sequence is bitonic if it first increases and then decreases, or can be circularly shifted to do
+ so. A half-cleaner compares each element of the first half of a bitonic sequence with the
+ corresponding element of the second half. Afterwards, both halves are bitonic, and every element
+ of the first half is at most as large as every element of the second half. The BITONIC-SORTER
+ applies half-cleaners recursively to both halves, and so sorts any bitonic sequence with a depth
+ of `lg n`.
+
+ Two sorted sequences make a bitonic sequence if the second one is reversed. Rather than reversing
+ it, the MERGER compares the `i`-th element from the start with the `i`-th element from the end in
+ its first stage, and then continues like the bitonic sorter. Finally, SORTER sorts both halves
+ recursively and merges them, with a depth of `lg n (lg n + 1) / 2`.
+
+ All of these require `n` to be a power of two. For other `n`, `sorter` builds the network for the
+ next power of two and leaves out all comparators on the wires beyond `n`.
+*/
+use super::{Comparator, Network};
+
+#[cfg(test)]
+mod test;
+
+/// BITONIC-SORTER: A network on `n` wires that sorts bitonic sequences.
+///
+/// ## Panics
+/// This function panics unless `n` is a power of two.
+pub fn bitonic_sorter(n: usize) -> Network {
+    assert!(n.is_power_of_two(), "{} is not a power of two", n);
+
+    let mut network = Network::new(n);
+    add_bitonic_sorter(&mut network, 0, n);
+    network
+}
+
+/// MERGER: A network on `n` wires that merges two sorted sequences of length `n / 2`.
+///
+/// ## Panics
+/// This function panics unless `n` is a power of two.
+pub fn merger(n: usize) -> Network {
+    assert!(n.is_power_of_two(), "{} is not a power of two", n);
+
+    let mut network = Network::new(n);
+    add_merger(&mut network, 0, n);
+    network
+}
+
+/// SORTER: A sorting network on `n` wires.
+pub fn sorter(n: usize) -> Network {
+    let m = n.next_power_of_two();
+
+    let mut network = Network::new(m);
+    add_sorter(&mut network, 0, m);
+    network.truncate(n)
+}
+
+// The following add the respective networks on the wires `start..start + n`.
+
+fn add_half_cleaner(network: &mut Network, start: usize, n: usize) {
+    for i in start..start + n / 2 {
+        network.push(Comparator::new(i, i + n / 2));
+    }
+}
+
+fn add_bitonic_sorter(network: &mut Network, start: usize, n: usize) {
+    if n > 1 {
+        add_half_cleaner(network, start, n);
+        add_bitonic_sorter(network, start, n / 2);
+        add_bitonic_sorter(network, start + n / 2, n / 2);
+    }
+}
+
+fn add_merger(network: &mut Network, start: usize, n: usize) {
+    if n > 1 {
+        for i in 0..n / 2 {
+            network.push(Comparator::new(start + i, start + n - 1 - i));
+        }
+        add_bitonic_sorter(network, start, n / 2);
+        add_bitonic_sorter(network, start + n / 2, n / 2);
+    }
+}
+
+fn add_sorter(network: &mut Network, start: usize, n: usize) {
+    if n > 1 {
+        add_sorter(network, start, n / 2);
+        add_sorter(network, start + n / 2, n / 2);
+        add_merger(network, start, n);
+    }
+}
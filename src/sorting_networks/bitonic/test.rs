@@ -0,0 +1,85 @@
+use quickcheck_macros::quickcheck;
+
+use super::{bitonic_sorter, merger, sorter};
+
+// All sequences of zeros and ones of length `n` that first increase and then decrease, or vice
+// versa.
+fn bitonic_zero_one_inputs(n: usize) -> Vec<Vec<u8>> {
+    let mut inputs = Vec::new();
+    for a in 0..=n {
+        for b in a..=n {
+            for (outer, inner) in [(0, 1), (1, 0)] {
+                let input: Vec<u8> = (0..n)
+                    .map(|i| if (a..b).contains(&i) { inner } else { outer })
+                    .collect();
+                inputs.push(input);
+            }
+        }
+    }
+    inputs
+}
+
+#[test]
+fn sorts_bitonic_sequences() {
+    for n in [1, 2, 4, 8, 16, 32] {
+        let network = bitonic_sorter(n);
+        assert_eq!(network.depth(), n.ilog2() as usize);
+
+        for mut input in bitonic_zero_one_inputs(n) {
+            network.apply(&mut input);
+            assert!(input.is_sorted(), "Bitonic sorter of {} wires fails", n);
+        }
+    }
+}
+
+#[test]
+fn merges() {
+    for n in [2, 4, 8, 16, 32] {
+        let network = merger(n);
+        let half = n / 2;
+
+        for a in 0..=half {
+            for b in 0..=half {
+                let mut input: Vec<u8> = (0..half)
+                    .map(|i| u8::from(i >= a))
+                    .chain((0..half).map(|i| u8::from(i >= b)))
+                    .collect();
+                network.apply(&mut input);
+                assert!(input.is_sorted(), "Merger of {} wires fails", n);
+            }
+        }
+    }
+}
+
+#[test]
+fn sorting_networks() {
+    for n in 0..=16 {
+        let network = sorter(n);
+        assert!(network.is_sorting_network(), "Sorter of {} wires fails", n);
+    }
+}
+
+#[test]
+fn sorter_measures() {
+    for k in 0..=6 {
+        let n = 1 << k;
+        let network = sorter(n);
+        assert_eq!(network.depth(), k * (k + 1) / 2);
+        assert_eq!(network.size(), n * k * (k + 1) / 4);
+    }
+}
+
+#[quickcheck]
+fn sorts(data: Vec<i32>) -> bool {
+    let mut data = data;
+    sorter(data.len()).apply(&mut data);
+    data.is_sorted()
+}
+
+// Edge case(s)
+
+#[test]
+#[should_panic]
+fn not_a_power_of_two() {
+    merger(6);
+}
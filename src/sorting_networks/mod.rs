@@ -0,0 +1,173 @@
+/*! # Chapter 27 (2nd edition) - Sorting Networks
+
+ A sorting network is a sorting algorithm whose sequence of comparisons is fixed in advance,
+ independently of the input. It consists of `n` wires carrying the values, and a sequence of
+ comparators, each of which connects two wires and swaps their values if they're out of order.
+ Since comparators touching disjoint wires can work at the same time, the interesting measures of a
+ network are its size, the number of comparators, and its depth, the length of the longest chain of
+ comparators depending on each other. The fixed structure makes sorting networks a good fit for
+ hardware, and for branch-free SIMD code sorting small arrays.
+
+ The 0-1 principle says that a network sorts all inputs if it sorts all `2^n` inputs consisting of
+ only zeros and ones. That gives a way to check a network for correctness that's much faster than
+ trying all `n!` permutations. We simulate 64 of those inputs at once, using one bit of a `u64` per
+ input on each wire.
+
+ The builders for the networks of the chapter live in the submodules: `bitonic` has the bitonic
+ sorter, the merging network and the sorting network built from them, and `odd_even_merge` has
+ Batcher's odd-even merge sort.
+*/
+pub mod bitonic;
+pub mod odd_even_merge;
+
+#[cfg(test)]
+mod test;
+
+/// A comparator between two wires, which puts the smaller value onto the wire `low` and the larger
+/// one onto the wire `high`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Comparator {
+    pub low: usize,
+    pub high: usize,
+}
+
+impl Comparator {
+    /// ## Panics
+    /// This function panics unless `low < high`.
+    pub fn new(low: usize, high: usize) -> Comparator {
+        assert!(low < high, "Comparator between {} and {}", low, high);
+        Comparator { low, high }
+    }
+}
+
+/// A comparison network: comparators on a number of wires, in the order they are applied.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Network {
+    wires: usize,
+    comparators: Vec<Comparator>,
+}
+
+impl Network {
+    /// An empty network on `wires` wires.
+    pub fn new(wires: usize) -> Network {
+        Network {
+            wires,
+            comparators: Vec::new(),
+        }
+    }
+
+    pub fn wires(&self) -> usize {
+        self.wires
+    }
+
+    pub fn comparators(&self) -> &[Comparator] {
+        &self.comparators
+    }
+
+    /// Append a comparator to the network.
+    ///
+    /// ## Panics
+    /// This function panics if the comparator doesn't fit onto the wires of the network.
+    pub fn push(&mut self, comparator: Comparator) {
+        assert!(
+            comparator.high < self.wires,
+            "Comparator on wire {} of a network of {} wires",
+            comparator.high,
+            self.wires
+        );
+        self.comparators.push(comparator);
+    }
+
+    /// The number of comparators.
+    pub fn size(&self) -> usize {
+        self.comparators.len()
+    }
+
+    /// The length of the longest chain of comparators, each of which depends on the output of the
+    /// one before.
+    pub fn depth(&self) -> usize {
+        self.layers().len()
+    }
+
+    /// Split the comparators into layers, such that the comparators within a layer touch disjoint
+    /// wires and can be applied in parallel. Every comparator ends up in the earliest layer
+    /// possible, and the number of layers is the depth of the network.
+    pub fn layers(&self) -> Vec<Vec<Comparator>> {
+        // The number of layers up to and including the last comparator on each wire.
+        let mut wire_depths = vec![0; self.wires];
+        let mut layers: Vec<Vec<Comparator>> = Vec::new();
+
+        for &c in &self.comparators {
+            let depth = wire_depths[c.low].max(wire_depths[c.high]);
+            if depth == layers.len() {
+                layers.push(Vec::new());
+            }
+
+            layers[depth].push(c);
+            wire_depths[c.low] = depth + 1;
+            wire_depths[c.high] = depth + 1;
+        }
+
+        layers
+    }
+
+    /// Run the values of `data` through the network.
+    ///
+    /// ## Panics
+    /// This function panics if the length of `data` differs from the number of wires.
+    pub fn apply<T>(&self, data: &mut [T])
+    where
+        T: PartialOrd,
+    {
+        assert_eq!(data.len(), self.wires, "Input doesn't match the wires");
+
+        for c in &self.comparators {
+            if data[c.high] < data[c.low] {
+                data.swap(c.low, c.high);
+            }
+        }
+    }
+
+    /// Check whether the network sorts all inputs, by the 0-1 principle. This tries all `2^n`
+    /// inputs of zeros and ones, so it is only feasible for networks of not too many wires.
+    ///
+    /// ## Panics
+    /// This function panics if the network has 64 wires or more.
+    pub fn is_sorting_network(&self) -> bool {
+        assert!(self.wires < 64, "Too many wires to check");
+
+        // Bit `i` of an input is the value on wire `i`. Every bit of the `u64`s below stands for a
+        // different input, so we check 64 of them at a time.
+        let mut wires = vec![0u64; self.wires];
+
+        (0..1u64 << self.wires).step_by(64).all(|batch| {
+            for (i, wire) in wires.iter_mut().enumerate() {
+                *wire = (0..64).fold(0, |bits, k| bits | (((batch + k) >> i) & 1) << k);
+            }
+
+            // The minimum of two bits is their conjunction, the maximum their disjunction.
+            for c in &self.comparators {
+                let (low, high) = (wires[c.low], wires[c.high]);
+                wires[c.low] = low & high;
+                wires[c.high] = low | high;
+            }
+
+            // Sorted means that no wire carries a one where the next one carries a zero.
+            wires.windows(2).all(|w| w[0] & !w[1] == 0)
+        })
+    }
+
+    // Build a network for `n` wires from one for `m >= n` wires, by leaving out all comparators
+    // touching any of the wires from `n` on. If the wires beyond `n` are thought of as carrying
+    // infinity, those comparators never swap anything, so a sorting network stays one.
+    fn truncate(self, n: usize) -> Network {
+        Network {
+            wires: n,
+            comparators: self
+                .comparators
+                .into_iter()
+                .filter(|c| c.high < n)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/*! # Problem 27-2 (2nd edition) - Odd-Even Merging Network
+
+ **Input:** A number of wires `n`
+
+ **Output:** A sorting network on `n` wires
+
+ **Time complexity:** `Θ(n lg^2 n)` comparators with a depth of `Θ(lg^2 n)`
+
+ Batcher's odd-even merge is an alternative to the bitonic MERGER. To merge two sorted sequences, it
+ recursively merges the elements at even indices of both sequences, and likewise those at odd
+ indices. The two results interleaved are almost sorted already: only adjacent pairs can still be
+ out of order, which one final layer of comparators takes care of. The recursion is just the same
+ one level down, with every other wire, so we pass along the distance between the wires involved.
+
+ Odd-even merge sort uses this merge just like SORTER uses MERGER. It has the same depth as the
+ bitonic sorting network, but fewer comparators, e.g. 19 instead of 24 for 8 wires. As with
+ `bitonic::sorter`, other `n` than powers of two are handled by leaving out wires.
+*/
+use super::{Comparator, Network};
+
+#[cfg(test)]
+mod test;
+
+/// A network on `n` wires that merges two sorted sequences of length `n / 2`.
+///
+/// ## Panics
+/// This function panics unless `n` is a power of two.
+pub fn odd_even_merger(n: usize) -> Network {
+    assert!(n.is_power_of_two(), "{} is not a power of two", n);
+
+    let mut network = Network::new(n);
+    if n > 1 {
+        add_odd_even_merger(&mut network, 0, n, 1);
+    }
+    network
+}
+
+/// Batcher's odd-even merge sort: A sorting network on `n` wires.
+pub fn odd_even_merge_sort(n: usize) -> Network {
+    let m = n.next_power_of_two();
+
+    let mut network = Network::new(m);
+    add_odd_even_merge_sort(&mut network, 0, m);
+    network.truncate(n)
+}
+
+// Add the merger of the wires `start, start + distance, ...` below `start + n`, where the first
+// and second half of these are sorted.
+fn add_odd_even_merger(network: &mut Network, start: usize, n: usize, distance: usize) {
+    let step = 2 * distance;
+
+    if step < n {
+        add_odd_even_merger(network, start, n, step);
+        add_odd_even_merger(network, start + distance, n, step);
+
+        for i in (start + distance..start + n - distance).step_by(step) {
+            network.push(Comparator::new(i, i + distance));
+        }
+    } else {
+        network.push(Comparator::new(start, start + distance));
+    }
+}
+
+fn add_odd_even_merge_sort(network: &mut Network, start: usize, n: usize) {
+    if n > 1 {
+        add_odd_even_merge_sort(network, start, n / 2);
+        add_odd_even_merge_sort(network, start + n / 2, n / 2);
+        add_odd_even_merger(network, start, n, 1);
+    }
+}
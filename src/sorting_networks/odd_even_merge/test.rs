@@ -0,0 +1,49 @@
+use quickcheck_macros::quickcheck;
+
+use super::{odd_even_merge_sort, odd_even_merger};
+use crate::sorting_networks::bitonic;
+
+#[test]
+fn merges() {
+    for n in [2, 4, 8, 16, 32] {
+        let network = odd_even_merger(n);
+        let half = n / 2;
+
+        for a in 0..=half {
+            for b in 0..=half {
+                let mut input: Vec<u8> = (0..half)
+                    .map(|i| u8::from(i >= a))
+                    .chain((0..half).map(|i| u8::from(i >= b)))
+                    .collect();
+                network.apply(&mut input);
+                assert!(input.is_sorted(), "Merger of {} wires fails", n);
+            }
+        }
+    }
+}
+
+#[test]
+fn sorting_networks() {
+    for n in 0..=16 {
+        let network = odd_even_merge_sort(n);
+        assert!(network.is_sorting_network(), "Sorter of {} wires fails", n);
+    }
+}
+
+#[test]
+fn measures() {
+    for k in 1..=6 {
+        let n = 1 << k;
+        let network = odd_even_merge_sort(n);
+        assert_eq!(network.depth(), k * (k + 1) / 2);
+        assert_eq!(network.size(), (k * k - k + 4) * (1 << k) / 4 - 1);
+        assert!(network.size() <= bitonic::sorter(n).size());
+    }
+}
+
+#[quickcheck]
+fn sorts(data: Vec<i32>) -> bool {
+    let mut data = data;
+    odd_even_merge_sort(data.len()).apply(&mut data);
+    data.is_sorted()
+}
@@ -0,0 +1,83 @@
+use quickcheck_macros::quickcheck;
+
+use super::{Comparator, Network};
+
+// A network sorting three wires: the largest value first sinks to wire 2, then the smaller two
+// are sorted.
+fn three_sorter() -> Network {
+    let mut network = Network::new(3);
+    network.push(Comparator::new(0, 1));
+    network.push(Comparator::new(1, 2));
+    network.push(Comparator::new(0, 1));
+    network
+}
+
+#[quickcheck]
+fn applies(a: i32, b: i32, c: i32) -> bool {
+    let mut data = [a, b, c];
+    three_sorter().apply(&mut data);
+    data.is_sorted()
+}
+
+#[test]
+fn measures() {
+    let network = three_sorter();
+    assert_eq!(network.size(), 3);
+    assert_eq!(network.depth(), 3);
+    assert!(network.is_sorting_network());
+}
+
+#[test]
+fn layers() {
+    let mut network = Network::new(4);
+    for (low, high) in [(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)] {
+        network.push(Comparator::new(low, high));
+    }
+
+    let layers = network.layers();
+    assert_eq!(layers.len(), network.depth());
+    assert_eq!(
+        layers,
+        vec![
+            vec![Comparator::new(0, 1), Comparator::new(2, 3)],
+            vec![Comparator::new(0, 2), Comparator::new(1, 3)],
+            vec![Comparator::new(1, 2)],
+        ]
+    );
+    assert!(network.is_sorting_network());
+}
+
+#[test]
+fn not_sorting() {
+    let mut network = three_sorter();
+    network.comparators.pop();
+    assert!(!network.is_sorting_network());
+}
+
+#[test]
+#[should_panic]
+fn comparator_out_of_order() {
+    Comparator::new(2, 1);
+}
+
+#[test]
+#[should_panic]
+fn comparator_out_of_range() {
+    Network::new(2).push(Comparator::new(1, 2));
+}
+
+#[test]
+#[should_panic]
+fn wrong_input_length() {
+    three_sorter().apply(&mut [1, 2]);
+}
+
+// Edge case(s)
+
+#[test]
+fn no_wires() {
+    let network = Network::new(0);
+    network.apply::<i32>(&mut []);
+    assert_eq!(network.depth(), 0);
+    assert!(network.is_sorting_network());
+}
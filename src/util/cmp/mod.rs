@@ -0,0 +1,11 @@
+use std::cmp::Ordering;
+
+/// Compare two elements of a partial order, treating incomparable elements as equal. This is what
+/// the comparison operators amount to: `a < b` and `a > b` are both false for incomparable `a` and
+/// `b`, so sorting algorithms phrased in terms of them never consider such elements out of order.
+pub fn partial_compare<T>(a: &T, b: &T) -> Ordering
+where
+    T: PartialOrd + ?Sized,
+{
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
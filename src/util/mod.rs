@@ -1 +1,3 @@
+pub mod cmp;
+pub mod random;
 pub mod slice;
@@ -0,0 +1,39 @@
+use std::{collections::hash_map::RandomState, hash::BuildHasher, ops::Range};
+
+/// A small xorshift* pseudo-random number generator, which is plenty for picking pivots and such.
+/// Do not use it for anything that needs to be unpredictable.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    /// A generator seeded from the standard library's per-process hashing keys.
+    pub fn new() -> Random {
+        Random::with_seed(RandomState::new().hash_one(0u64))
+    }
+
+    /// A generator with a fixed seed, for reproducible sequences.
+    pub fn with_seed(seed: u64) -> Random {
+        // The all-zero state is a fixed point of xorshift, so we nudge the seed away from it.
+        Random { state: seed | 1 }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number from `range`, which must not be empty. The modulo bias is negligible for the
+    /// range sizes we deal with.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(
+            !range.is_empty(),
+            "Cannot pick from empty range {:?}",
+            range
+        );
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+    }
+}
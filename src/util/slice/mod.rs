@@ -1,4 +1,6 @@
-use std::ops;
+use std::{mem, ops};
+
+use crate::instrumentation;
 
 pub fn range<R>(range: R, bounds: ops::RangeTo<usize>) -> ops::Range<usize>
 where
@@ -33,3 +35,63 @@ where
 
     ops::Range { start, end }
 }
+
+/// Move every element `data[i]` to index `destinations[i]` in `O(n)` time, using nothing but swaps.
+/// `destinations` must be a permutation of `0..data.len()`; it is rearranged along with `data`.
+pub fn permute<T>(data: &mut [T], destinations: &mut [usize]) {
+    assert_eq!(
+        data.len(),
+        destinations.len(),
+        "Permutation must match the length of the data"
+    );
+
+    // Every swap puts the element at `i` into its final place at `j`, and brings the element from
+    // `j` over to `i`, where it takes over `j`'s old destination. So we keep swapping until the
+    // element that belongs at `i` has arrived there.
+    for i in 0..data.len() {
+        while destinations[i] != i {
+            let j = destinations[i];
+            swap(data, i, j);
+            destinations.swap(i, j);
+        }
+    }
+}
+
+// The algorithms move elements around with the following functions rather than the methods of the
+// standard library, which they wrap. A plain move can't be noticed by the elements themselves, so
+// this is how `instrumentation` gets to count them.
+
+/// Exchange `data[a]` and `data[b]`, like `<[T]>::swap`.
+pub fn swap<T>(data: &mut [T], a: usize, b: usize) {
+    data.swap(a, b);
+    instrumentation::count_swap(&data[a]);
+}
+
+/// Exchange the values behind `a` and `b`, like `mem::swap`. Each counts as a write to the slice it
+/// belongs to.
+pub fn swap_values<T>(a: &mut T, b: &mut T) {
+    mem::swap(a, b);
+    instrumentation::count_writes(a, 1);
+    instrumentation::count_writes(b, 1);
+}
+
+/// Exchange the elements of `a` and `b`, like `<[T]>::swap_with_slice`. Each element counts as a
+/// write to the slice it belongs to.
+pub fn swap_with_slice<T>(a: &mut [T], b: &mut [T]) {
+    a.swap_with_slice(b);
+    if let (Some(first_a), Some(first_b)) = (a.first(), b.first()) {
+        instrumentation::count_writes(first_a, a.len());
+        instrumentation::count_writes(first_b, b.len());
+    }
+}
+
+/// Rotate `data` to the right by `k` elements, like `<[T]>::rotate_right`. Every element that moves
+/// counts as a write.
+pub fn rotate_right<T>(data: &mut [T], k: usize) {
+    data.rotate_right(k);
+    if let Some(first) = data.first() {
+        if !k.is_multiple_of(data.len()) {
+            instrumentation::count_writes(first, data.len());
+        }
+    }
+}
@@ -0,0 +1,219 @@
+/*! # Problem 6-3 - Young Tableaus
+
+ An `m * n` Young tableau is a matrix whose rows are sorted from left to right and whose columns
+ are sorted from top to bottom. Some of its entries may be `∞`, which we treat as empty, so that a
+ tableau can hold anywhere between zero and `mn` elements. It is a close cousin of the heap: the
+ smallest element always sits in the top left corner, and EXTRACT-MIN and INSERT both work by
+ floating a single element along a path through the matrix, which takes `O(m + n)` time.
+
+ This also gives rise to a sorting algorithm: inserting `n^2` elements into an `n * n` tableau and
+ extracting them again sorts them in `O(n^3)` time. Searching the tableau for a value is handled
+ by `searching::young_tableau_search`, which works for any row- and column-sorted matrix.
+*/
+use crate::{
+    matrix_multiplication::{Matrix, MatrixDimensions, MatrixIndex},
+    searching::young_tableau_search::young_tableau_search,
+    util::slice,
+};
+
+#[cfg(test)]
+mod test;
+
+/// An entry of a Young tableau. Note that the order of the variants matters: the derived
+/// `PartialOrd` orders all finite values before `Infinity`, just as we need.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Entry<T> {
+    Finite(T),
+    Infinity,
+}
+
+/// A Young tableau of elements of type `T`, stored in a `Matrix`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YoungTableau<T> {
+    matrix: Matrix<Entry<T>>,
+}
+
+impl<T> YoungTableau<T>
+where
+    T: PartialOrd,
+{
+    /// Construct an empty tableau of the given dimensions.
+    pub fn new(dimensions: MatrixDimensions) -> YoungTableau<T> {
+        YoungTableau {
+            matrix: Matrix::from_fn(dimensions, || Entry::Infinity),
+        }
+    }
+
+    /// Get the dimensions of the tableau.
+    pub fn dimensions(&self) -> MatrixDimensions {
+        self.matrix.dimensions()
+    }
+
+    /// Get the matrix the tableau is stored in.
+    pub fn matrix(&self) -> &Matrix<Entry<T>> {
+        &self.matrix
+    }
+
+    /// Whether the tableau holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.minimum().is_none()
+    }
+
+    /// Whether the tableau has no room for another element.
+    pub fn is_full(&self) -> bool {
+        match self.last_index() {
+            Some(index) => self.matrix[index] != Entry::Infinity,
+            None => true,
+        }
+    }
+
+    /// The smallest element of the tableau, if any.
+    pub fn minimum(&self) -> Option<&T> {
+        let MatrixDimensions { columns, rows } = self.dimensions();
+        if columns == 0 || rows == 0 {
+            return None;
+        }
+
+        match &self.matrix[MatrixIndex { x: 0, y: 0 }] {
+            Entry::Finite(value) => Some(value),
+            Entry::Infinity => None,
+        }
+    }
+
+    /// Remove and return the smallest element of the tableau.
+    pub fn extract_min(&mut self) -> Option<T> {
+        self.minimum()?;
+
+        let mut index = MatrixIndex { x: 0, y: 0 };
+        let min = std::mem::replace(&mut self.matrix[index], Entry::Infinity);
+
+        // The hole left behind is now filled with `∞`, which has to sink towards the bottom right
+        // corner: we repeatedly swap it with the smaller of its right and lower neighbors, much
+        // like MAX-HEAPIFY does with the larger of its children.
+        let MatrixDimensions { columns, rows } = self.dimensions();
+        loop {
+            let mut smallest = index;
+
+            let right = MatrixIndex {
+                x: index.x + 1,
+                ..index
+            };
+            if right.x < columns && self.matrix[right] < self.matrix[smallest] {
+                smallest = right;
+            }
+
+            let below = MatrixIndex {
+                y: index.y + 1,
+                ..index
+            };
+            if below.y < rows && self.matrix[below] < self.matrix[smallest] {
+                smallest = below;
+            }
+
+            if smallest == index {
+                break;
+            }
+
+            self.matrix.swap(index, smallest);
+            index = smallest;
+        }
+
+        match min {
+            Entry::Finite(value) => Some(value),
+            Entry::Infinity => unreachable!(),
+        }
+    }
+
+    /// Add `value` to the tableau. If the tableau is full, `value` is handed back as an error.
+    pub fn insert(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        // A tableau that isn't full has an empty bottom right corner, from where we let the new
+        // value rise towards the top left by swapping it with the larger of its left and upper
+        // neighbors.
+        let mut index = self.last_index().unwrap();
+        self.matrix[index] = Entry::Finite(value);
+
+        loop {
+            let mut largest = index;
+
+            if index.x > 0 {
+                let left = MatrixIndex {
+                    x: index.x - 1,
+                    ..index
+                };
+                if self.matrix[left] > self.matrix[largest] {
+                    largest = left;
+                }
+            }
+
+            if index.y > 0 {
+                let above = MatrixIndex {
+                    y: index.y - 1,
+                    ..index
+                };
+                if self.matrix[above] > self.matrix[largest] {
+                    largest = above;
+                }
+            }
+
+            if largest == index {
+                return Ok(());
+            }
+
+            self.matrix.swap(index, largest);
+            index = largest;
+        }
+    }
+
+    /// Find the position of `value` in the tableau, in `O(m + n)` time.
+    pub fn find(&self, value: T) -> Option<MatrixIndex> {
+        young_tableau_search(&self.matrix, Entry::Finite(value))
+    }
+
+    fn last_index(&self) -> Option<MatrixIndex> {
+        let MatrixDimensions { columns, rows } = self.dimensions();
+        if columns == 0 || rows == 0 {
+            return None;
+        }
+
+        Some(MatrixIndex {
+            x: columns - 1,
+            y: rows - 1,
+        })
+    }
+}
+
+/// Sort `data` by inserting all of it into a square Young tableau and extracting the elements
+/// again. For `n^2` elements, this takes `O(n^3)` time.
+pub fn young_tableau_sort<T>(data: &mut [T])
+where
+    T: PartialOrd,
+{
+    let mut side = 0;
+    while side * side < data.len() {
+        side += 1;
+    }
+
+    let mut tableau = YoungTableau::new(MatrixDimensions {
+        columns: side,
+        rows: side,
+    });
+
+    // The tableau holds references to the elements along with their indices, so that we can tell
+    // where each element goes without having to clone it.
+    for (i, element) in data.iter().enumerate() {
+        // By choice of `side`, there is always enough room.
+        let _ = tableau.insert((element, i));
+    }
+
+    let mut destinations = vec![0; data.len()];
+    for destination in 0..data.len() {
+        let (_, source) = tableau.extract_min().unwrap();
+        destinations[source] = destination;
+    }
+
+    slice::permute(data, &mut destinations);
+}
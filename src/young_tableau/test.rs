@@ -0,0 +1,121 @@
+use quickcheck_macros::quickcheck;
+
+use super::{young_tableau_sort, Entry, YoungTableau};
+use crate::{
+    matrix_multiplication::{MatrixDimensions, MatrixIndex},
+    testing::vector::{ElementOf, NoElementOf},
+};
+
+fn is_young_tableau<T: PartialOrd>(tableau: &YoungTableau<T>) -> bool {
+    let MatrixDimensions { columns, rows } = tableau.dimensions();
+    let matrix = tableau.matrix();
+
+    (0..rows).all(|y| {
+        (0..columns).all(|x| {
+            let here = &matrix[MatrixIndex { x, y }];
+            (x + 1 >= columns || here <= &matrix[MatrixIndex { x: x + 1, y }])
+                && (y + 1 >= rows || here <= &matrix[MatrixIndex { x, y: y + 1 }])
+        })
+    })
+}
+
+fn tableau_of(data: &[i32]) -> YoungTableau<i32> {
+    let mut tableau = YoungTableau::new(MatrixDimensions {
+        columns: data.len() / 2 + 1,
+        rows: 2,
+    });
+
+    for &d in data {
+        tableau.insert(d).unwrap();
+    }
+
+    tableau
+}
+
+#[quickcheck]
+fn sortedness(mut data: Vec<i32>) -> bool {
+    young_tableau_sort(&mut data);
+    data.is_sorted()
+}
+
+#[quickcheck]
+fn sortedness_preservation(mut data: Vec<i32>) -> bool {
+    let mut data_copy = data.clone();
+    young_tableau_sort(&mut data);
+    data_copy.sort_unstable();
+    data == data_copy
+}
+
+#[quickcheck]
+fn sort_without_clone(data: Vec<i32>) -> bool {
+    // A type that can be neither cloned nor copied.
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Unique(i32);
+
+    let mut expected = data.clone();
+    expected.sort_unstable();
+
+    let mut unique: Vec<_> = data.into_iter().map(Unique).collect();
+    young_tableau_sort(&mut unique);
+    unique.iter().map(|u| u.0).eq(expected)
+}
+
+#[quickcheck]
+fn insertion_keeps_shape(data: Vec<i32>) -> bool {
+    let mut tableau = YoungTableau::new(MatrixDimensions {
+        columns: 3,
+        rows: data.len() / 3 + 1,
+    });
+
+    data.into_iter()
+        .all(|d| tableau.insert(d).is_ok() && is_young_tableau(&tableau))
+}
+
+#[quickcheck]
+fn extraction_keeps_shape(data: Vec<i32>) -> bool {
+    let mut tableau = tableau_of(&data);
+    let mut expected = data;
+    expected.sort_unstable();
+
+    expected
+        .into_iter()
+        .all(|e| tableau.extract_min() == Some(e) && is_young_tableau(&tableau))
+        && tableau.is_empty()
+}
+
+#[quickcheck]
+fn finds_members(v: ElementOf<i32>) -> bool {
+    let tableau = tableau_of(&v.data);
+    matches!(
+        tableau.find(v.element),
+        Some(i) if tableau.matrix()[i] == Entry::Finite(v.element)
+    )
+}
+
+#[quickcheck]
+fn doesnt_find_unmember(v: NoElementOf<i32>) -> bool {
+    tableau_of(&v.data).find(v.element).is_none()
+}
+
+// Edge case(s)
+
+#[test]
+fn no_data() {
+    let mut nothing: Vec<i32> = Vec::new();
+    young_tableau_sort(&mut nothing);
+    assert!(nothing.is_empty());
+}
+
+#[test]
+fn full_tableau() {
+    let mut tableau = YoungTableau::new(MatrixDimensions {
+        columns: 2,
+        rows: 1,
+    });
+
+    assert_eq!(tableau.insert(2), Ok(()));
+    assert_eq!(tableau.insert(1), Ok(()));
+    assert!(tableau.is_full());
+    assert_eq!(tableau.insert(3), Err(3));
+    assert_eq!(tableau.minimum(), Some(&1));
+}